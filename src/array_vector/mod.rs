@@ -0,0 +1,335 @@
+#[cfg(test)]
+mod tests;
+
+mod iter;
+pub use iter::{Iter, IterMut, IntoIter};
+
+use core::ptr::{drop_in_place, slice_from_raw_parts_mut, read as ptr_read};
+use core::ops::{Index, IndexMut, Deref, DerefMut};
+use core::slice::{self, SliceIndex};
+use core::mem::MaybeUninit;
+use core::iter::FromIterator;
+use core::option::Option;
+use core::fmt;
+
+
+/// Shorthand Syntax for creating a new `ArrayVector`, the capacity is inferred from the type.
+/// Panics if more values are given than the `ArrayVector` can hold.
+/// ## Example:
+/// ```rust
+/// let array: ArrayVector<i32, 4> = array_vector![1, 2, 3];
+/// assert_eq!(array, [1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! array_vector {
+    ($($e:expr), *) => {
+        {
+            #[allow(unused_mut)]
+            let mut vec = $crate::array_vector::ArrayVector::new();
+            $(
+                vec.push($e);
+            )*
+            vec
+        }
+    };
+}
+
+
+/// `Vector` with a fixed capacity of `N` values stored inline, it never allocates.
+/// ## Fields:
+/// ```rust
+/// buffer: [MaybeUninit<T>; N] // Storage for the values, only the first `length` are initialized.
+/// length: usize // Length of the ArrayVector.
+/// ```
+pub struct ArrayVector<T, const N: usize> {
+    buffer: [MaybeUninit<T>; N],
+    length: usize,
+}
+
+
+impl<T, const N: usize> ArrayVector<T, N> {
+    pub const fn new() -> Self {
+        /* An array of `MaybeUninit` is valid without being initialized. */
+        let buffer = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
+        return Self { buffer, length: 0 };
+    }
+
+    pub const fn capacity(&self) -> usize {
+        return N;
+    }
+
+    pub const fn len(&self) -> usize {
+        return self.length;
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        return self.length == 0;
+    }
+
+    pub const fn is_full(&self) -> bool {
+        return self.length == N;
+    }
+
+    /// Pushs a new value into the `ArrayVector`.
+    /// Panics if the `ArrayVector` is full, see `try_push` for a non-panicking alternative.
+    /// ## Example:
+    /// ```rust
+    /// let mut array: ArrayVector<i32, 2> = ArrayVector::new();
+    /// array.push(1);
+    /// array.push(2);
+    /// assert_eq!(array, [1, 2]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        if self.try_push(value).is_err() {
+            panic!("ArrayVector is full (capacity is {}).", N);
+        }
+    }
+
+    /// Tries to push a new value into the `ArrayVector`, handing it back if the `ArrayVector` is full.
+    /// ## Example:
+    /// ```rust
+    /// let mut array: ArrayVector<i32, 1> = ArrayVector::new();
+    /// assert_eq!(array.try_push(1), Ok(()));
+    /// assert_eq!(array.try_push(2), Err(2));
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.length == N { return Err(value); }
+
+        self.buffer[self.length] = MaybeUninit::new(value);
+        self.length += 1;
+        return Ok(());
+    }
+
+    /// Removes the last value from the `ArrayVector` and returns it.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.length == 0 { return None; }
+
+        self.length -= 1;
+        unsafe { return Some(ptr_read(self.buffer[self.length].as_ptr())); }
+    }
+
+    /// Drops every value after the first `length` values, has no effect if `length` is not smaller than the length.
+    pub fn truncate(&mut self, length: usize) {
+        if length >= self.length { return; }
+
+        let count = self.length - length;
+        self.length = length;
+
+        unsafe { drop_in_place(slice_from_raw_parts_mut(self.as_mut_ptr().add(length), count)); }
+    }
+
+    /// Drops every value within the `ArrayVector`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns a reference to the value at the given index if it exists.
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        return self.as_slice().get(idx);
+    }
+
+    /// Returns a mutable reference to the value at the given index if it exists.
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        return self.as_mut_slice().get_mut(idx);
+    }
+
+    /// Returns a raw pointer to the buffer of the `ArrayVector`.
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *const T {
+        return self.buffer.as_ptr() as *const T;
+    }
+
+    /// Returns a mutable raw pointer to the buffer of the `ArrayVector`.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        return self.buffer.as_mut_ptr() as *mut T;
+    }
+
+    /// Returns a slice over every value within the `ArrayVector`.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { return slice::from_raw_parts(self.as_ptr(), self.length); }
+    }
+
+    /// Returns a mutable slice over every value within the `ArrayVector`.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { return slice::from_raw_parts_mut(self.as_mut_ptr(), self.length); }
+    }
+
+    /// Returns an iterator over references to the values of the `ArrayVector`.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        return Iter::new(self.as_slice());
+    }
+
+    /// Returns an iterator over mutable references to the values of the `ArrayVector`.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        return IterMut::new(self.as_mut_slice());
+    }
+}
+
+
+impl<T: PartialEq, const N: usize> ArrayVector<T, N> {
+    /// Searches through the `ArrayVector` to find a value that matches `finding`, returning its index if found.
+    pub fn search(&self, finding: T) -> Option<usize> {
+        return self.iter().position(|value| *value == finding);
+    }
+}
+
+
+impl<T, const N: usize> Default for ArrayVector<T, N> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+
+impl<T, const N: usize> Drop for ArrayVector<T, N> {
+    fn drop(&mut self) {
+        unsafe { drop_in_place(slice_from_raw_parts_mut(self.as_mut_ptr(), self.length)); }
+    }
+}
+
+
+impl<T: Clone, const N: usize> Clone for ArrayVector<T, N> {
+    fn clone(&self) -> Self {
+        return self.iter().cloned().collect();
+    }
+}
+
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVector<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f.debug_list().entries(self.iter()).finish();
+    }
+}
+
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<ArrayVector<T, M>> for ArrayVector<T, N> {
+    fn eq(&self, other: &ArrayVector<T, M>) -> bool {
+        return self.as_slice() == other.as_slice();
+    }
+}
+
+
+impl<T: Eq, const N: usize> Eq for ArrayVector<T, N> {  }
+
+
+impl<T: PartialEq, const N: usize> PartialEq<[T]> for ArrayVector<T, N> {
+    fn eq(&self, other: &[T]) -> bool {
+        return self.as_slice() == other;
+    }
+}
+
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<[T; M]> for ArrayVector<T, N> {
+    fn eq(&self, other: &[T; M]) -> bool {
+        return self.as_slice() == other;
+    }
+}
+
+
+impl<T, const N: usize> Deref for ArrayVector<T, N> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        return self.as_slice();
+    }
+}
+
+
+impl<T, const N: usize> DerefMut for ArrayVector<T, N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        return self.as_mut_slice();
+    }
+}
+
+
+impl<T, const N: usize> AsRef<[T]> for ArrayVector<T, N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        return self.as_slice();
+    }
+}
+
+
+impl<T, const N: usize> AsMut<[T]> for ArrayVector<T, N> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [T] {
+        return self.as_mut_slice();
+    }
+}
+
+
+impl<T, I: SliceIndex<[T]>, const N: usize> Index<I> for ArrayVector<T, N> {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        return &self.as_slice()[index];
+    }
+}
+
+
+impl<T, I: SliceIndex<[T]>, const N: usize> IndexMut<I> for ArrayVector<T, N> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        return &mut self.as_mut_slice()[index];
+    }
+}
+
+
+/// Panics if the iterator yields more values than the `ArrayVector` can hold.
+impl<T, const N: usize> FromIterator<T> for ArrayVector<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        return result;
+    }
+}
+
+
+/// Panics if the iterator yields more values than the `ArrayVector` has room for.
+impl<T, const N: usize> Extend<T> for ArrayVector<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter { self.push(value); }
+    }
+}
+
+
+impl<T, const N: usize> IntoIterator for ArrayVector<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return IntoIter::new(self);
+    }
+}
+
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVector<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVector<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter_mut();
+    }
+}
@@ -0,0 +1,143 @@
+use super::ArrayVector;
+use crate::array_vector;
+use std::rc::Rc;
+
+
+#[test]
+fn push() {
+    let mut array: ArrayVector<i32, 3> = ArrayVector::new();
+    assert!(array.is_empty());
+    assert_eq!(array.capacity(), 3);
+
+    array.push(1);
+    array.push(2);
+    array.push(3);
+    assert!(array.is_full());
+    assert_eq!(array, [1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn push_full() {
+    let mut array: ArrayVector<i32, 2> = array_vector![1, 2];
+    array.push(3);
+}
+
+#[test]
+fn try_push() {
+    let mut array: ArrayVector<String, 1> = ArrayVector::new();
+    assert_eq!(array.try_push(String::from("a")), Ok(()));
+    assert_eq!(array.try_push(String::from("b")), Err(String::from("b")));
+    assert_eq!(array.len(), 1);
+
+    let mut empty: ArrayVector<i32, 0> = ArrayVector::new();
+    assert_eq!(empty.try_push(1), Err(1));
+}
+
+#[test]
+fn pop() {
+    let mut array: ArrayVector<i32, 4> = array_vector![1, 2];
+    assert_eq!(array.pop(), Some(2));
+    assert_eq!(array.pop(), Some(1));
+    assert_eq!(array.pop(), None);
+}
+
+#[test]
+fn truncate() {
+    let mut array: ArrayVector<i32, 4> = array_vector![1, 2, 3, 4];
+    array.truncate(5);
+    assert_eq!(array.len(), 4);
+    array.truncate(2);
+    assert_eq!(array, [1, 2]);
+    array.clear();
+    assert!(array.is_empty());
+}
+
+#[test]
+fn get() {
+    let mut array: ArrayVector<i32, 4> = array_vector![1, 2, 3];
+    assert_eq!(array.get(2), Some(&3));
+    assert_eq!(array.get(3), None);
+
+    *array.get_mut(0).unwrap() = 0;
+    assert_eq!(array, [0, 2, 3]);
+}
+
+#[test]
+fn index() {
+    let mut array: ArrayVector<i32, 4> = array_vector![1, 2, 3];
+    assert_eq!(array[1], 2);
+    assert_eq!(&array[1 ..], &[2, 3]);
+
+    array[0] = 5;
+    array.sort();
+    assert_eq!(array, [2, 3, 5]);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+    let array: ArrayVector<i32, 4> = array_vector![1, 2, 3];
+    let _ = array[3];
+}
+
+#[test]
+fn search() {
+    let array: ArrayVector<i32, 4> = array_vector![1, 2, 3];
+    assert_eq!(array.search(2), Some(1));
+    assert_eq!(array.search(4), None);
+}
+
+#[test]
+fn iter() {
+    let mut array: ArrayVector<i32, 4> = (1 ..= 3).collect();
+    for value in &mut array { *value *= 2; }
+
+    let mut iter = array.iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(&6));
+    assert_eq!(iter.next(), Some(&2));
+
+    let collected: Vec<&i32> = (&array).into_iter().collect();
+    assert_eq!(collected, vec![&2, &4, &6]);
+    assert_eq!(format!("{:?}", array), "[2, 4, 6]");
+}
+
+#[test]
+fn into_iter() {
+    let array: ArrayVector<String, 4> = array_vector![String::from("a"), String::from("b"), String::from("c")];
+    let mut iter = array.into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back().as_deref(), Some("c"));
+    assert_eq!(iter.as_slice(), &[String::from("a"), String::from("b")]);
+    assert_eq!(iter.next().as_deref(), Some("a"));
+    assert_eq!(iter.next().as_deref(), Some("b"));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn clone() {
+    let array: ArrayVector<String, 2> = array_vector![String::from("a")];
+    let cloned = array.clone();
+    assert_eq!(array, cloned);
+}
+
+#[test]
+fn drops_values_once() {
+    let rc = Rc::new(());
+
+    let array: ArrayVector<Rc<()>, 8> = (0 .. 6).map(|_| rc.clone()).collect();
+    assert_eq!(Rc::strong_count(&rc), 7);
+
+    let mut iter = array.into_iter();
+    let first = iter.next().unwrap();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 2);
+
+    let mut array: ArrayVector<Rc<()>, 8> = (0 .. 4).map(|_| rc.clone()).collect();
+    array.truncate(1);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    drop((first, array));
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
@@ -0,0 +1,365 @@
+use super::node::{Node, Link};
+use super::BinaryTree;
+
+use alloc::collections::VecDeque;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+
+/// Walks the `Node`s of a Tree in ascending order, yielding pointers to them.
+/// Keeps an explicit stack for each end, so deep Trees cannot overflow the call stack.
+/// 
+/// ## Fields:
+/// ```rust
+/// front: Vec<NonNull<Node<T>>> // Path to the next Node yielded from the front.
+/// back: Vec<NonNull<Node<T>>> // Path to the next Node yielded from the back.
+/// remaining: usize // Amount of Nodes left to yield.
+/// ```
+pub(crate) struct RawIter<T> {
+    front: Vec<NonNull<Node<T>>>,
+    back: Vec<NonNull<Node<T>>>,
+    remaining: usize,
+}
+
+
+/// Struct for iterating over a `BinaryTree` in ascending order.
+pub struct Iter<'a, T> {
+    raw: RawIter<T>,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+
+/// Struct for iterating over a `BinaryTree` in pre-order, visiting each `Node` before its children.
+pub struct PreOrder<'a, T> {
+    stack: Vec<NonNull<Node<T>>>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+
+/// Struct for iterating over a `BinaryTree` in post-order, visiting each `Node` after its children.
+/// The `bool` paired with each `Node` determines if its children have already been pushed.
+pub struct PostOrder<'a, T> {
+    stack: Vec<(NonNull<Node<T>>, bool)>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+
+/// Struct for iterating over a `BinaryTree` in level-order, visiting the `Node`s breadth-first.
+pub struct LevelOrder<'a, T> {
+    queue: VecDeque<NonNull<Node<T>>>,
+    remaining: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+
+/// Struct for converting a `BinaryTree` into an `Iterator` over its values in ascending order.
+/// The Tree is flattened into a doubly linked chain upon creation, `left` pointing to the previous `Node`
+/// and `right` pointing to the next, after which each `Node` is freed as its value is yielded.
+pub struct IntoIter<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    length: usize,
+}
+
+
+impl<T> RawIter<T> {
+    pub(crate) fn new(root: Link<T>, length: usize) -> Self {
+        let mut iter = Self {
+            front: Vec::new(),
+            back: Vec::new(),
+            remaining: length,
+        };
+
+        iter.push_left(root);
+        iter.push_right(root);
+        return iter;
+    }
+
+    /// Pushes `link` and its chain of left children onto the front stack.
+    fn push_left(&mut self, mut link: Link<T>) {
+        while let Some(ptr) = link {
+            self.front.push(ptr);
+            link = unsafe { ptr.as_ref().left };
+        }
+    }
+
+    /// Pushes `link` and its chain of right children onto the back stack.
+    fn push_right(&mut self, mut link: Link<T>) {
+        while let Some(ptr) = link {
+            self.back.push(ptr);
+            link = unsafe { ptr.as_ref().right };
+        }
+    }
+}
+
+
+impl<T> Iterator for RawIter<T> {
+    type Item = NonNull<Node<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 { return None; }
+
+        let ptr = self.front.pop()?;
+        self.push_left(unsafe { ptr.as_ref().right });
+        self.remaining -= 1;
+        return Some(ptr);
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.remaining, Some(self.remaining));
+    }
+}
+
+
+impl<T> DoubleEndedIterator for RawIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 { return None; }
+
+        let ptr = self.back.pop()?;
+        self.push_right(unsafe { ptr.as_ref().left });
+        self.remaining -= 1;
+        return Some(ptr);
+    }
+}
+
+
+impl<T> ExactSizeIterator for RawIter<T> {  }
+impl<T> FusedIterator for RawIter<T> {  }
+
+
+impl<T> Clone for RawIter<T> {
+    fn clone(&self) -> Self {
+        return Self {
+            front: self.front.clone(),
+            back: self.back.clone(),
+            remaining: self.remaining,
+        };
+    }
+}
+
+
+impl<'a, T> Iter<'a, T> {
+    pub(super) fn new(tree: &'a BinaryTree<T>) -> Self {
+        return Self {
+            raw: tree.raw_iter(),
+            marker: PhantomData,
+        };
+    }
+}
+
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.raw.next().map(|ptr| unsafe { &(*ptr.as_ptr()).data });
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.raw.size_hint();
+    }
+}
+
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.raw.next_back().map(|ptr| unsafe { &(*ptr.as_ptr()).data });
+    }
+}
+
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {  }
+impl<'a, T> FusedIterator for Iter<'a, T> {  }
+
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        return Self { raw: self.raw.clone(), marker: PhantomData };
+    }
+}
+
+
+impl<'a, T> PreOrder<'a, T> {
+    pub(super) fn new(tree: &'a BinaryTree<T>) -> Self {
+        return Self {
+            stack: tree.root.into_iter().collect(),
+            remaining: tree.length,
+            marker: PhantomData,
+        };
+    }
+}
+
+
+impl<'a, T> Iterator for PreOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = unsafe { self.stack.pop()?.as_ref() };
+        self.stack.extend(node.right);
+        self.stack.extend(node.left);
+        self.remaining -= 1;
+        return Some(&node.data);
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.remaining, Some(self.remaining));
+    }
+}
+
+
+impl<'a, T> ExactSizeIterator for PreOrder<'a, T> {  }
+impl<'a, T> FusedIterator for PreOrder<'a, T> {  }
+
+
+impl<'a, T> PostOrder<'a, T> {
+    pub(super) fn new(tree: &'a BinaryTree<T>) -> Self {
+        return Self {
+            stack: tree.root.map(|ptr| (ptr, false)).into_iter().collect(),
+            remaining: tree.length,
+            marker: PhantomData,
+        };
+    }
+}
+
+
+impl<'a, T> Iterator for PostOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((ptr, expanded)) = self.stack.pop() {
+            let node = unsafe { ptr.as_ref() };
+
+            if expanded {
+                self.remaining -= 1;
+                return Some(&node.data);
+            }
+
+            self.stack.push((ptr, true));
+            self.stack.extend(node.right.map(|ptr| (ptr, false)));
+            self.stack.extend(node.left.map(|ptr| (ptr, false)));
+        }
+
+        return None;
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.remaining, Some(self.remaining));
+    }
+}
+
+
+impl<'a, T> ExactSizeIterator for PostOrder<'a, T> {  }
+impl<'a, T> FusedIterator for PostOrder<'a, T> {  }
+
+
+impl<'a, T> LevelOrder<'a, T> {
+    pub(super) fn new(tree: &'a BinaryTree<T>) -> Self {
+        return Self {
+            queue: tree.root.into_iter().collect(),
+            remaining: tree.length,
+            marker: PhantomData,
+        };
+    }
+}
+
+
+impl<'a, T> Iterator for LevelOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = unsafe { self.queue.pop_front()?.as_ref() };
+        self.queue.extend(node.left);
+        self.queue.extend(node.right);
+        self.remaining -= 1;
+        return Some(&node.data);
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.remaining, Some(self.remaining));
+    }
+}
+
+
+impl<'a, T> ExactSizeIterator for LevelOrder<'a, T> {  }
+impl<'a, T> FusedIterator for LevelOrder<'a, T> {  }
+
+
+impl<T> IntoIter<T> {
+    pub(super) fn new(mut tree: BinaryTree<T>) -> Self {
+        let length = tree.length;
+        let head = Node::flatten(tree.root.take());
+
+        let (mut previous, mut current) = (None, head);
+        while let Some(ptr) = current {
+            unsafe {
+                (*ptr.as_ptr()).left = previous;
+                previous = current;
+                current = ptr.as_ref().right;
+            }
+        }
+
+        return Self { head, tail: previous, length };
+    }
+}
+
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = unsafe { Box::from_raw(self.head?.as_ptr()) };
+        self.head = node.right;
+
+        match self.head {
+            Some(ptr) => unsafe { (*ptr.as_ptr()).left = None; },
+            None => self.tail = None,
+        }
+
+        self.length -= 1;
+        return Some(node.data);
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.length, Some(self.length));
+    }
+}
+
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let node = unsafe { Box::from_raw(self.tail?.as_ptr()) };
+        self.tail = node.left;
+
+        match self.tail {
+            Some(ptr) => unsafe { (*ptr.as_ptr()).right = None; },
+            None => self.head = None,
+        }
+
+        self.length -= 1;
+        return Some(node.data);
+    }
+}
+
+
+impl<T> ExactSizeIterator for IntoIter<T> {  }
+impl<T> FusedIterator for IntoIter<T> {  }
+
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        for _ in self { }
+    }
+}
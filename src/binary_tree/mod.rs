@@ -1,19 +1,44 @@
+#[cfg(test)]
+mod tests;
+
 pub(crate) mod node;
+use node::{Node, Link, compare};
 
-use node::Node;
+mod iter;
+pub(crate) use iter::RawIter;
+pub use iter::{Iter, PreOrder, PostOrder, LevelOrder, IntoIter};
 
-use std::option::Option;
-use std::ptr::NonNull;
+mod range;
+pub(crate) use range::{RawRange, before_start, after_end};
+pub use range::Range;
+
+use core::ops::RangeBounds;
+use core::option::Option;
+use core::cmp::Ordering;
+use core::ptr::NonNull;
+use alloc::boxed::Box;
 
 
 /// Struct for creating a `BinaryTree`.
+/// The Tree balances itself as an AVL Tree, rotating `Node`s on insertion and removal so its height stays `O(log n)`,
+/// even when values are inserted in sorted order.
+/// 
 /// ## Fields:
 /// ```rust
 /// root: Option<NonNull<Node<T>>> // Root Node of the Tree.
+/// length: usize // Amount of Nodes within the Tree.
 /// ```
 #[derive(Debug)]
 pub struct BinaryTree<T> {
     root: Option<NonNull<Node<T>>>,
+    length: usize,
+}
+
+
+/// Returns a reference to the data of a `Node` found within a `BinaryTree`, bound to the lifetime of the Tree.
+#[inline(always)]
+fn data<'a, T>(link: Option<NonNull<Node<T>>>) -> Option<&'a T> {
+    return link.map(|ptr| unsafe { ptr.as_ref() }.get_data());
 }
 
 
@@ -21,25 +46,454 @@ impl<T> BinaryTree<T> {
     pub const fn new() -> Self {
         return Self {
             root: None,
+            length: 0,
         };
     }
 
+    /// Returns the amount of values within the `BinaryTree`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    /// tree.insert(2);
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        return self.length;
+    }
+
+    /// Returns a `bool` that determines if the `BinaryTree` is empty.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        return self.root.is_none();
+    }
+
+    /// Clears the `BinaryTree`, dropping every value and freeing its `Node`s.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(1);
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// ```
     pub fn clear(&mut self) {
         *self = Self::new();
     }
+
+    /// Returns a reference to the `index`-th smallest value within the `BinaryTree`, counting from `0`.
+    /// Runs in `O(log n)` as every `Node` keeps track of the size of its subtree.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// for x in [30, 10, 20] { tree.insert(x); }
+    /// assert_eq!(tree.select(0), Some(&10));
+    /// assert_eq!(tree.select(2), Some(&30));
+    /// assert_eq!(tree.select(3), None);
+    /// ```
+    #[inline]
+    pub fn select(&self, index: usize) -> Option<&T> {
+        return data(Node::select(self.root, index));
+    }
+
+    /// Returns an `Iterator` over the values of the `BinaryTree` in ascending order.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    /// tree.insert(3);
+    /// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// assert_eq!(tree.iter().rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        return Iter::new(self);
+    }
+
+    /// Returns an `Iterator` over the values of the `BinaryTree`, visiting each `Node` before its children.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    /// tree.insert(3);
+    /// assert_eq!(tree.pre_order().collect::<Vec<_>>(), vec![&2, &1, &3]);
+    /// ```
+    #[inline]
+    pub fn pre_order(&self) -> PreOrder<'_, T> {
+        return PreOrder::new(self);
+    }
+
+    /// Returns an `Iterator` over the values of the `BinaryTree`, visiting each `Node` after its children.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    /// tree.insert(3);
+    /// assert_eq!(tree.post_order().collect::<Vec<_>>(), vec![&1, &3, &2]);
+    /// ```
+    #[inline]
+    pub fn post_order(&self) -> PostOrder<'_, T> {
+        return PostOrder::new(self);
+    }
+
+    /// Returns an `Iterator` over the values of the `BinaryTree`, visiting the `Node`s breadth-first.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    /// tree.insert(3);
+    /// tree.insert(4);
+    /// assert_eq!(tree.level_order().collect::<Vec<_>>(), vec![&2, &1, &3, &4]);
+    /// ```
+    #[inline]
+    pub fn level_order(&self) -> LevelOrder<'_, T> {
+        return LevelOrder::new(self);
+    }
+
+    /// Returns a reference to the smallest value within the `BinaryTree`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    /// assert_eq!(tree.first(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        return data(Node::first(self.root));
+    }
+
+    /// Returns a reference to the largest value within the `BinaryTree`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    /// assert_eq!(tree.last(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        return data(Node::last(self.root));
+    }
+
+    /// Removes the smallest value within the `BinaryTree` and returns it.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(2);
+    /// tree.insert(1);
+    /// assert_eq!(tree.pop_first(), Some(1));
+    /// assert_eq!(tree.pop_first(), Some(2));
+    /// assert_eq!(tree.pop_first(), None);
+    /// ```
+    pub fn pop_first(&mut self) -> Option<T> {
+        let node = Node::remove_first(&mut self.root)?;
+        self.length -= 1;
+        return Some(node.data);
+    }
+
+    /// Removes the largest value within the `BinaryTree` and returns it.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(1);
+    /// tree.insert(2);
+    /// assert_eq!(tree.pop_last(), Some(2));
+    /// assert_eq!(tree.pop_last(), Some(1));
+    /// assert_eq!(tree.pop_last(), None);
+    /// ```
+    pub fn pop_last(&mut self) -> Option<T> {
+        let node = Node::remove_last(&mut self.root)?;
+        self.length -= 1;
+        return Some(node.data);
+    }
 }
 
 
-impl<T: PartialOrd> BinaryTree<T> {
-    pub fn insert(&mut self, data: T) {
-        match self.root {
-            Some(mut ptr) => unsafe { ptr.as_mut().insert(data); },
-
-            None => unsafe {
-                let node = Box::new(Node::new(data));
-                self.root = Some(NonNull::new_unchecked(Box::into_raw(node)));
-            },
+/* Comparator Methods, shared with the ordered containers built on `BinaryTree` */
+impl<T> BinaryTree<T> {
+    /// Inserts `data`, ordering it against present values with `cmp`.
+    /// Returns a mutable reference to the stored value, alongside `data` if it was rejected due to an equal value being present.
+    pub(crate) fn insert_by<F: FnMut(&T, &T) -> Ordering>(&mut self, data: T, mut cmp: F) -> (&mut T, Option<T>) {
+        let (ptr, rejected) = Node::insert(&mut self.root, data, &mut cmp);
+        if rejected.is_none() { self.length += 1; }
+        return (unsafe { &mut (*ptr.as_ptr()).data }, rejected);
+    }
+
+    /// Returns a reference to the value that `cmp` reports as `Ordering::Equal`.
+    /// `cmp` receives each visited value and returns where the searched value lies relative to it.
+    #[inline]
+    pub(crate) fn get_by<F: FnMut(&T) -> Ordering>(&self, cmp: F) -> Option<&T> {
+        return data(Node::find(self.root, cmp));
+    }
+
+    /// Returns a mutable reference to the value that `cmp` reports as `Ordering::Equal`.
+    #[inline]
+    pub(crate) fn get_mut_by<F: FnMut(&T) -> Ordering>(&mut self, cmp: F) -> Option<&mut T> {
+        return Node::find(self.root, cmp).map(|ptr| unsafe { &mut (*ptr.as_ptr()).data });
+    }
+
+    /// Removes the value that `cmp` reports as `Ordering::Equal` and returns it.
+    pub(crate) fn take_by<F: FnMut(&T) -> Ordering>(&mut self, mut cmp: F) -> Option<T> {
+        let node = Node::remove(&mut self.root, &mut cmp)?;
+        self.length -= 1;
+        return Some(node.data);
+    }
+
+    /// Removes the value stored at `target` and returns it, matching it by address rather than by value.
+    /// `cmp` steers the search towards `target` and is never called on the value at `target`,
+    /// so it may read from `target` without overlapping the mutable borrow of its `Node`.
+    pub(crate) fn take_identical<F: FnMut(&T) -> Ordering>(&mut self, target: NonNull<T>, mut cmp: F) -> Option<T> {
+        let node = Node::remove_identical(&mut self.root, target.as_ptr(), &mut cmp)?;
+        self.length -= 1;
+        return Some(node.data);
+    }
+
+    /// Returns a walker over the `Node`s of the `BinaryTree` in ascending order.
+    #[inline]
+    pub(crate) fn raw_iter(&self) -> RawIter<T> {
+        return RawIter::new(self.root, self.length);
+    }
+
+    /// Returns a walker over the `Node`s of the `BinaryTree` for which neither `before` nor `after` hold, in ascending order.
+    #[inline]
+    pub(crate) fn raw_range<F: FnMut(&T) -> bool, G: FnMut(&T) -> bool>(&self, before: F, after: G) -> RawRange<T> {
+        return RawRange::new(self.root, before, after);
+    }
+
+    /// Removes every value for which neither `before` nor `after` hold, returning them within a new `BinaryTree`.
+    /// The removed Nodes are chained in ascending order and rebuilt into a balanced Tree in `O(n)`.
+    pub(crate) fn extract_by<F: FnMut(&T) -> bool, G: FnMut(&T) -> bool>(&mut self, mut before: F, mut after: G) -> Self {
+        let (mut vine, mut tail): (Link<T>, Link<T>) = (None, None);
+        let mut length = 0;
+
+        while let Some(node) = Node::remove_lower_bound(&mut self.root, &mut before, &mut after) {
+            self.length -= 1;
+            length += 1;
+
+            /* Nodes are removed in ascending order, so each one is chained after every Node removed before it. */
+            let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(node)) };
+
+            match tail {
+                Some(last) => unsafe { (*last.as_ptr()).right = Some(ptr) },
+                None => vine = Some(ptr),
+            }
+
+            tail = Some(ptr);
         }
+
+        return Self { root: Node::from_vine(&mut vine, length), length };
+    }
+}
+
+
+impl<T: PartialOrd> BinaryTree<T> {
+    /// Inserts `data` into the `BinaryTree`, returning a `bool` that determines if it was not already present.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// assert!(tree.insert(10));
+    /// assert!(!tree.insert(10));
+    /// ```
+    pub fn insert(&mut self, data: T) -> bool {
+        return self.insert_by(data, compare).1.is_none();
+    }
+
+    /// Returns a `bool` that determines if a value equal to `value` is within the `BinaryTree`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<&str> = BinaryTree::new();
+    /// tree.insert("Find");
+    /// assert!(tree.contains(&"Find"));
+    /// assert!(!tree.contains(&"Lose"));
+    /// ```
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        return self.get(value).is_some();
+    }
+
+    /// Returns a reference to the value within the `BinaryTree` that is equal to `value`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(5);
+    /// assert_eq!(tree.get(&5), Some(&5));
+    /// assert_eq!(tree.get(&6), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, value: &T) -> Option<&T> {
+        return self.get_by(|e| compare(value, e));
+    }
+
+    /// Returns a reference to the greatest value within the `BinaryTree` that is less than or equal to `value`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(10);
+    /// tree.insert(20);
+    /// assert_eq!(tree.floor(&15), Some(&10));
+    /// assert_eq!(tree.floor(&20), Some(&20));
+    /// assert_eq!(tree.floor(&5), None);
+    /// ```
+    #[inline]
+    pub fn floor(&self, value: &T) -> Option<&T> {
+        return data(Node::below(self.root, true, |e| compare(value, e)));
+    }
+
+    /// Returns a reference to the least value within the `BinaryTree` that is greater than or equal to `value`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(10);
+    /// tree.insert(20);
+    /// assert_eq!(tree.ceiling(&15), Some(&20));
+    /// assert_eq!(tree.ceiling(&10), Some(&10));
+    /// assert_eq!(tree.ceiling(&25), None);
+    /// ```
+    #[inline]
+    pub fn ceiling(&self, value: &T) -> Option<&T> {
+        return data(Node::above(self.root, true, |e| compare(value, e)));
+    }
+
+    /// Returns a reference to the greatest value within the `BinaryTree` that is strictly less than `value`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(10);
+    /// tree.insert(20);
+    /// assert_eq!(tree.predecessor(&20), Some(&10));
+    /// assert_eq!(tree.predecessor(&10), None);
+    /// ```
+    #[inline]
+    pub fn predecessor(&self, value: &T) -> Option<&T> {
+        return data(Node::below(self.root, false, |e| compare(value, e)));
+    }
+
+    /// Returns a reference to the least value within the `BinaryTree` that is strictly greater than `value`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(10);
+    /// tree.insert(20);
+    /// assert_eq!(tree.successor(&10), Some(&20));
+    /// assert_eq!(tree.successor(&20), None);
+    /// ```
+    #[inline]
+    pub fn successor(&self, value: &T) -> Option<&T> {
+        return data(Node::above(self.root, false, |e| compare(value, e)));
+    }
+
+    /// Returns the amount of values within the `BinaryTree` that are less than `value`, in `O(log n)`.
+    /// If `value` is present, this is the index it would be returned at by `select`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// for x in [30, 10, 20] { tree.insert(x); }
+    /// assert_eq!(tree.rank(&20), 1);
+    /// assert_eq!(tree.rank(&25), 2);
+    /// assert_eq!(tree.rank(&5), 0);
+    /// ```
+    #[inline]
+    pub fn rank(&self, value: &T) -> usize {
+        return Node::rank(self.root, |e| compare(value, e));
+    }
+
+    /// Returns an `Iterator` over the values of the `BinaryTree` that lie within `range`, in ascending order.
+    /// Only the subtrees overlapping the range are visited.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// for x in 0 .. 10 { tree.insert(x); }
+    /// assert_eq!(tree.range(3 .. 6).collect::<Vec<_>>(), vec![&3, &4, &5]);
+    /// assert_eq!(tree.range(7 ..).rev().collect::<Vec<_>>(), vec![&9, &8, &7]);
+    /// ```
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Range<'_, T> {
+        return Range::new(self.raw_range(
+            |e| before_start(range.start_bound(), |bound| compare(e, bound)),
+            |e| after_end(range.end_bound(), |bound| compare(e, bound)),
+        ));
+    }
+
+    /// Removes every value within `range` from the `BinaryTree`, returning an `Iterator` over them in ascending order.
+    /// The values are removed even if the returned `Iterator` is not consumed.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// for x in 0 .. 6 { tree.insert(x); }
+    /// assert_eq!(tree.drain_range(1 ..= 3).collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&0, &4, &5]);
+    /// ```
+    pub fn drain_range<R: RangeBounds<T>>(&mut self, range: R) -> IntoIter<T> {
+        return self.extract_by(
+            |e| before_start(range.start_bound(), |bound| compare(e, bound)),
+            |e| after_end(range.end_bound(), |bound| compare(e, bound)),
+        ).into_iter();
+    }
+
+    /// Splits the `BinaryTree` in two, returning a new Tree containing every value greater than or equal to `value`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// for x in 0 .. 6 { tree.insert(x); }
+    /// let upper = tree.split_off(&3);
+    /// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+    /// assert_eq!(upper.iter().collect::<Vec<_>>(), vec![&3, &4, &5]);
+    /// ```
+    pub fn split_off(&mut self, value: &T) -> Self {
+        return self.extract_by(|e| compare(e, value) == Ordering::Less, |_| false);
+    }
+
+    /// Removes the value equal to `value` from the `BinaryTree`, returning a `bool` that determines if it was present.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<&str> = BinaryTree::new();
+    /// tree.insert("Remove");
+    /// assert!(tree.remove(&"Remove"));
+    /// assert!(!tree.remove(&"Remove"));
+    /// ```
+    pub fn remove(&mut self, value: &T) -> bool {
+        return self.take(value).is_some();
+    }
+
+    /// Removes the value equal to `value` from the `BinaryTree` and returns it, if it was present.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// tree.insert(7);
+    /// assert_eq!(tree.take(&7), Some(7));
+    /// assert_eq!(tree.take(&7), None);
+    /// ```
+    pub fn take(&mut self, value: &T) -> Option<T> {
+        return self.take_by(|e| compare(value, e));
     }
 }
 
@@ -51,34 +505,46 @@ impl<T> Default for BinaryTree<T> {
 }
 
 
-#[cfg(test)]
-mod tests {
-    use super::BinaryTree;
-
-    #[test]
-    fn default() {
-        let tree: BinaryTree<i32> = BinaryTree::default();
-        assert_eq!(tree.root, None);
-    }
-
-    #[test]
-    #[ignore]
-    fn insert() {
-        // TODO: Complete Test
-        let mut tree = BinaryTree::new();
-        tree.insert(10);
-        tree.insert(15);
-        tree.insert(5);
-        tree.insert(20);
-    }
-
-    #[test]
-    #[ignore]
-    fn clear() {
-        // TODO: Complete Test, Needs `PartialEq` Trait
-        let mut tree = BinaryTree::new();
-        tree.insert("Swag");
-        tree.clear();
-        // assert_eq!(tree, BinaryTree::new());
-    }
-}
\ No newline at end of file
+impl<T> Drop for BinaryTree<T> {
+    fn drop(&mut self) {
+        /* Flattening the Tree first allows every `Node` to be freed in a single pass, without recursion. */
+        let mut current = Node::flatten(self.root.take());
+
+        while let Some(ptr) = current {
+            let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+            current = node.right;
+        }
+    }
+}
+
+
+impl<T: PartialEq> PartialEq for BinaryTree<T> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.length == other.length && self.iter().eq(other.iter());
+    }
+}
+
+
+impl<T: Eq> Eq for BinaryTree<T> {  }
+
+
+impl<T> IntoIterator for BinaryTree<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return IntoIter::new(self);
+    }
+}
+
+
+impl<'a, T> IntoIterator for &'a BinaryTree<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
@@ -1,32 +1,145 @@
-use std::option::Option;
-use std::ptr::NonNull;
-use std::fmt;
+use core::cmp::Ordering;
+use core::option::Option;
+use core::ptr::{self, NonNull};
+use core::fmt;
+use alloc::boxed::Box;
+
+
+/// Nullable pointer to a `Node`, used for the root of a Tree and the children of every `Node`.
+pub type Link<T> = Option<NonNull<Node<T>>>;
 
 
 /// Struct for creating the `Node`s of a `BinaryTree`.
 /// ## Fields:
 /// ```rust
-/// left: Option<NonNull<Node<T>>> // Left child Node within the Tree.
-/// right: Option<NonNull<Node<T>>> // Right child Node within the Tree.
-/// data: T // Data of the Node.
+/// pub left: Option<NonNull<Node<T>>> // Left child Node within the Tree.
+/// pub right: Option<NonNull<Node<T>>> // Right child Node within the Tree.
+/// pub height: usize // Height of the subtree rooted at this Node, used for AVL balancing.
+/// pub size: usize // Amount of Nodes within the subtree rooted at this Node, used for order statistics.
+/// pub data: T // Data of the Node.
 /// ```
 #[derive(Debug)]
 pub struct Node<T> {
-    left: Option<NonNull<Node<T>>>,
-    right: Option<NonNull<Node<T>>>,
-    data: T,
+    pub left: Link<T>,
+    pub right: Link<T>,
+    pub height: usize,
+    pub size: usize,
+    pub data: T,
+}
+
+
+/// Compares two values the way the Tree orders them, incomparable values are treated as greater.
+pub fn compare<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    return a.partial_cmp(b).unwrap_or(Ordering::Greater);
 }
 
 
+#[allow(dead_code)]
 impl<T> Node<T> {
     pub const fn new(data: T) -> Self {
         return Self {
             left: None,
             right: None,
+            height: 1,
+            size: 1,
             data,
         };
     }
 
+    /// Returns the height of the subtree beneath `link`, `0` if it is empty.
+    #[inline(always)]
+    pub fn height(link: Link<T>) -> usize {
+        return link.map_or(0, |ptr| unsafe { ptr.as_ref().height });
+    }
+
+    /// Returns the amount of `Node`s within the subtree beneath `link`.
+    #[inline(always)]
+    pub fn size(link: Link<T>) -> usize {
+        return link.map_or(0, |ptr| unsafe { ptr.as_ref().size });
+    }
+
+    /// Recomputes the cached height and size of this `Node` from its children.
+    #[inline(always)]
+    fn update(&mut self) {
+        self.height = 1 + Self::height(self.left).max(Self::height(self.right));
+        self.size = 1 + Self::size(self.left) + Self::size(self.right);
+    }
+
+    /// Rotates the subtree beneath `link` to the left, lifting its right child into its place.
+    fn rotate_left(link: &mut Link<T>) {
+        unsafe {
+            let mut ptr = link.expect("Cannot rotate an empty subtree.");
+            let mut right = ptr.as_ref().right.expect("Cannot rotate without a right child.");
+
+            ptr.as_mut().right = right.as_ref().left;
+            ptr.as_mut().update();
+            right.as_mut().left = Some(ptr);
+            right.as_mut().update();
+            *link = Some(right);
+        }
+    }
+
+    /// Rotates the subtree beneath `link` to the right, lifting its left child into its place.
+    fn rotate_right(link: &mut Link<T>) {
+        unsafe {
+            let mut ptr = link.expect("Cannot rotate an empty subtree.");
+            let mut left = ptr.as_ref().left.expect("Cannot rotate without a left child.");
+
+            ptr.as_mut().left = left.as_ref().right;
+            ptr.as_mut().update();
+            left.as_mut().right = Some(ptr);
+            left.as_mut().update();
+            *link = Some(left);
+        }
+    }
+
+    /// Restores the AVL invariant for the subtree beneath `link`, assuming both of its children already satisfy it.
+    /// The heights of the children differ by at most one afterwards, keeping the height of the Tree `O(log n)`.
+    fn balance(link: &mut Link<T>) {
+        let node = match *link {
+            Some(ptr) => unsafe { &mut *ptr.as_ptr() },
+            None => return,
+        };
+
+        node.update();
+        let (left, right) = (Self::height(node.left), Self::height(node.right));
+
+        if left > right + 1 {
+            let child = unsafe { node.left.unwrap().as_ref() };
+            if Self::height(child.left) < Self::height(child.right) { Self::rotate_left(&mut node.left); }
+            Self::rotate_right(link);
+        } else if right > left + 1 {
+            let child = unsafe { node.right.unwrap().as_ref() };
+            if Self::height(child.right) < Self::height(child.left) { Self::rotate_right(&mut node.right); }
+            Self::rotate_left(link);
+        }
+    }
+
+    /// Inserts `data` beneath `link` unless `cmp` reports it equal to a present value, rebalancing on the way back up.
+    /// Returns the `Node` holding the value, alongside `data` if it was rejected due to an equal value being present.
+    pub fn insert<F: FnMut(&T, &T) -> Ordering>(link: &mut Link<T>, data: T, cmp: &mut F) -> (NonNull<Node<T>>, Option<T>) {
+        let ptr = match *link {
+            Some(ptr) => ptr,
+
+            None => {
+                let node = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(Node::new(data)))) };
+                *link = Some(node);
+                return (node, None);
+            },
+        };
+
+        let node = unsafe { &mut *ptr.as_ptr() };
+
+        let result = match cmp(&data, &node.data) {
+            Ordering::Less => Self::insert(&mut node.left, data, cmp),
+            Ordering::Greater => Self::insert(&mut node.right, data, cmp),
+            Ordering::Equal => return (ptr, Some(data)),
+        };
+
+        if result.1.is_none() { Self::balance(link); }
+        return result;
+    }
+
     pub const fn get_data(&self) -> &T {
         return &self.data;
     }
@@ -34,25 +147,268 @@ impl<T> Node<T> {
     pub fn get_data_mut(&mut self) -> &mut T {
         return &mut self.data;
     }
-}
 
+    /// Returns the `Node` beneath `link` that `cmp` reports as `Ordering::Equal`.
+    /// `cmp` receives the data of each visited `Node` and returns where the searched value lies relative to it.
+    pub fn find<F: FnMut(&T) -> Ordering>(link: Link<T>, mut cmp: F) -> Link<T> {
+        let mut current = link;
 
-impl<T: PartialOrd> Node<T> {
-    pub fn insert(&mut self, data: T) {
-        if self.data == data { return; }
+        while let Some(ptr) = current {
+            let node = unsafe { ptr.as_ref() };
 
-        let target;
-        if data < self.data { target = &mut self.left; }
-        else { target = &mut self.right; }
+            current = match cmp(&node.data) {
+                Ordering::Less => node.left,
+                Ordering::Greater => node.right,
+                Ordering::Equal => return Some(ptr),
+            };
+        }
 
-        match target {
-            &mut Some(ptr) => unsafe { (*ptr.as_ptr()).insert(data); },
+        return None;
+    }
 
-            &mut None => unsafe {
-                let node = Box::new(Node::new(data));
-                *target = Some(NonNull::new_unchecked(Box::into_raw(node)));
-            },
+    /// Returns the amount of values beneath `link` that are less than the searched value.
+    /// `cmp` receives the data of each visited `Node` and returns where the searched value lies relative to it.
+    pub fn rank<F: FnMut(&T) -> Ordering>(link: Link<T>, mut cmp: F) -> usize {
+        let (mut current, mut rank) = (link, 0);
+
+        while let Some(ptr) = current {
+            let node = unsafe { ptr.as_ref() };
+
+            current = match cmp(&node.data) {
+                Ordering::Less => node.left,
+                Ordering::Equal => return rank + Self::size(node.left),
+                Ordering::Greater => { rank += Self::size(node.left) + 1; node.right },
+            };
         }
+
+        return rank;
+    }
+
+    /// Returns the `Node` holding the `index`-th smallest value beneath `link`, counting from `0`.
+    pub fn select(link: Link<T>, mut index: usize) -> Link<T> {
+        let mut current = link;
+
+        while let Some(ptr) = current {
+            let node = unsafe { ptr.as_ref() };
+            let left = Self::size(node.left);
+
+            current = match index.cmp(&left) {
+                Ordering::Less => node.left,
+                Ordering::Equal => return Some(ptr),
+                Ordering::Greater => { index -= left + 1; node.right },
+            };
+        }
+
+        return None;
+    }
+
+    /// Returns the `Node` holding the smallest value beneath `link`.
+    pub fn first(link: Link<T>) -> Link<T> {
+        let mut current = link?;
+        while let Some(ptr) = unsafe { current.as_ref().left } { current = ptr; }
+        return Some(current);
+    }
+
+    /// Returns the `Node` holding the largest value beneath `link`.
+    pub fn last(link: Link<T>) -> Link<T> {
+        let mut current = link?;
+        while let Some(ptr) = unsafe { current.as_ref().right } { current = ptr; }
+        return Some(current);
+    }
+
+    /// Returns the `Node` holding the largest value below the searched value, or equal to it if `inclusive`.
+    pub fn below<F: FnMut(&T) -> Ordering>(link: Link<T>, inclusive: bool, mut cmp: F) -> Link<T> {
+        let (mut current, mut found) = (link, None);
+
+        while let Some(ptr) = current {
+            let node = unsafe { ptr.as_ref() };
+
+            current = match cmp(&node.data) {
+                Ordering::Equal if inclusive => return Some(ptr),
+                Ordering::Less | Ordering::Equal => node.left,
+                Ordering::Greater => { found = Some(ptr); node.right },
+            };
+        }
+
+        return found;
+    }
+
+    /// Returns the `Node` holding the smallest value above the searched value, or equal to it if `inclusive`.
+    pub fn above<F: FnMut(&T) -> Ordering>(link: Link<T>, inclusive: bool, mut cmp: F) -> Link<T> {
+        let (mut current, mut found) = (link, None);
+
+        while let Some(ptr) = current {
+            let node = unsafe { ptr.as_ref() };
+
+            current = match cmp(&node.data) {
+                Ordering::Equal if inclusive => return Some(ptr),
+                Ordering::Greater | Ordering::Equal => node.right,
+                Ordering::Less => { found = Some(ptr); node.left },
+            };
+        }
+
+        return found;
+    }
+
+    /// Rotates the Tree beneath `link` into a chain of `right` children in ascending order without recursion,
+    /// returning the `Node` at the start of the chain.
+    pub fn flatten(link: Link<T>) -> Link<T> {
+        let mut head = link;
+        let mut slot: *mut Link<T> = &mut head;
+
+        unsafe {
+            while let Some(mut ptr) = *slot {
+                match ptr.as_ref().left {
+                    Some(mut left) => {
+                        /* Rotate right, lifting the left child above its parent. */
+                        ptr.as_mut().left = left.as_ref().right;
+                        left.as_mut().right = Some(ptr);
+                        *slot = Some(left);
+                    },
+
+                    None => slot = &mut ptr.as_mut().right,
+                }
+            }
+        }
+
+        return head;
+    }
+
+    /// Detaches the `Node` holding the smallest value beneath `link`, returning ownership of it.
+    pub fn remove_first(link: &mut Link<T>) -> Option<Box<Node<T>>> {
+        let ptr = (*link)?;
+
+        unsafe {
+            if (*ptr.as_ptr()).left.is_some() {
+                let removed = Self::remove_first(&mut (*ptr.as_ptr()).left);
+                Self::balance(link);
+                return removed;
+            }
+
+            let mut node = Box::from_raw(ptr.as_ptr());
+            *link = node.right.take();
+            return Some(node);
+        }
+    }
+
+    /// Detaches the `Node` holding the largest value beneath `link`, returning ownership of it.
+    pub fn remove_last(link: &mut Link<T>) -> Option<Box<Node<T>>> {
+        let ptr = (*link)?;
+
+        unsafe {
+            if (*ptr.as_ptr()).right.is_some() {
+                let removed = Self::remove_last(&mut (*ptr.as_ptr()).right);
+                Self::balance(link);
+                return removed;
+            }
+
+            let mut node = Box::from_raw(ptr.as_ptr());
+            *link = node.left.take();
+            return Some(node);
+        }
+    }
+
+    /// Detaches the `Node` at `link` itself, replacing it with its children and returning ownership of it.
+    /// The caller is responsible for rebalancing the subtree at `link` afterwards.
+    fn unlink(link: &mut Link<T>) -> Box<Node<T>> {
+        unsafe {
+            let mut node = Box::from_raw(link.expect("Cannot unlink an empty subtree.").as_ptr());
+
+            *link = match (node.left.take(), node.right.take()) {
+                (None, None) => None,
+                (Some(child), None) | (None, Some(child)) => Some(child),
+
+                (Some(left), Some(right)) => {
+                    /* Replace the removed `Node` with its in-order successor. */
+                    let mut right = Some(right);
+                    let mut successor = Self::remove_first(&mut right)
+                        .expect("Right subtree cannot be empty.");
+
+                    successor.left = Some(left);
+                    successor.right = right;
+                    Some(NonNull::new_unchecked(Box::into_raw(successor)))
+                },
+            };
+
+            return node;
+        }
+    }
+
+    /// Detaches the `Node` holding the smallest value beneath `link` for which `before` does not hold,
+    /// returning ownership of it unless `after` holds for it as well.
+    pub fn remove_lower_bound<F, G>(link: &mut Link<T>, before: &mut F, after: &mut G) -> Option<Box<Node<T>>>
+    where
+        F: FnMut(&T) -> bool,
+        G: FnMut(&T) -> bool,
+    {
+        let node = unsafe { &mut *(*link)?.as_ptr() };
+
+        let removed = if before(&node.data) {
+            Self::remove_lower_bound(&mut node.right, before, after)
+        } else {
+            match Self::remove_lower_bound(&mut node.left, before, after) {
+                Some(removed) => Some(removed),
+                None if after(&node.data) => None,
+                None => Some(Self::unlink(link)),
+            }
+        };
+
+        if removed.is_some() { Self::balance(link); }
+        return removed;
+    }
+
+    /// Builds a balanced subtree out of the first `count` Nodes of `vine`, advancing `vine` past them.
+    /// `vine` has to chain its Nodes through their `right` children in ascending order, left children empty,
+    /// as the Nodes are placed by position alone without comparing or rebalancing anything, taking `O(count)`.
+    pub fn from_vine(vine: &mut Link<T>, count: usize) -> Link<T> {
+        if count == 0 { return None; }
+
+        let left = Self::from_vine(vine, count / 2);
+        let ptr = vine.expect("Vine holds fewer Nodes than requested.");
+
+        unsafe {
+            let node = &mut *ptr.as_ptr();
+            *vine = node.right;
+            node.left = left;
+            node.right = Self::from_vine(vine, count - count / 2 - 1);
+            node.update();
+        }
+
+        return Some(ptr);
+    }
+
+    /// Detaches the `Node` beneath `link` that `cmp` reports as `Ordering::Equal`, returning ownership of it.
+    /// `cmp` receives the data of each visited `Node` and returns where the searched value lies relative to it.
+    pub fn remove<F: FnMut(&T) -> Ordering>(link: &mut Link<T>, cmp: &mut F) -> Option<Box<Node<T>>> {
+        let node = unsafe { &mut *(*link)?.as_ptr() };
+
+        let removed = match cmp(&node.data) {
+            Ordering::Less => Self::remove(&mut node.left, cmp),
+            Ordering::Greater => Self::remove(&mut node.right, cmp),
+            Ordering::Equal => Some(Self::unlink(link)),
+        };
+
+        if removed.is_some() { Self::balance(link); }
+        return removed;
+    }
+
+    /// Detaches the `Node` whose data lives at `target`, returning ownership of it.
+    /// `target` is matched by address, `cmp` only steers the search through the other `Node`s and is never given the data at `target`.
+    pub fn remove_identical<F: FnMut(&T) -> Ordering>(link: &mut Link<T>, target: *const T, cmp: &mut F) -> Option<Box<Node<T>>> {
+        let node = unsafe { &mut *(*link)?.as_ptr() };
+
+        let removed = if ptr::eq(&node.data, target) {
+            Some(Self::unlink(link))
+        } else {
+            match cmp(&node.data) {
+                Ordering::Less => Self::remove_identical(&mut node.left, target, cmp),
+                Ordering::Greater => Self::remove_identical(&mut node.right, target, cmp),
+                Ordering::Equal => None,
+            }
+        };
+
+        if removed.is_some() { Self::balance(link); }
+        return removed;
     }
 }
 
@@ -73,7 +429,12 @@ impl<T: fmt::Display> fmt::Display for Node<T> {
 
 #[cfg(test)]
 mod tests {
-    use super::Node;
+    use super::{Node, Link};
+    use std::ptr::NonNull;
+
+    fn leaf(data: i32) -> Link<i32> {
+        return Some(unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(Node::new(data)))) });
+    }
 
     #[test]
     fn create_node() {
@@ -98,4 +459,19 @@ mod tests {
         let node: Node<i32> = Node::new(777);
         assert_eq!(format!("{}", node), "777");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn remove_first_last() {
+        let mut root = leaf(2);
+        unsafe {
+            (*root.unwrap().as_ptr()).left = leaf(1);
+            (*root.unwrap().as_ptr()).right = leaf(3);
+        }
+
+        assert_eq!(Node::remove_first(&mut root).map(|n| n.data), Some(1));
+        assert_eq!(Node::remove_last(&mut root).map(|n| n.data), Some(3));
+        assert_eq!(Node::remove_last(&mut root).map(|n| n.data), Some(2));
+        assert!(root.is_none());
+        assert!(Node::remove_first(&mut root).is_none());
+    }
+}
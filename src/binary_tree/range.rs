@@ -0,0 +1,182 @@
+use super::node::{Node, Link};
+
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::cmp::Ordering;
+use core::ops::Bound;
+use core::ptr::NonNull;
+use alloc::vec::Vec;
+
+
+/// Returns a `bool` that determines if a value lies before the `start` bound of a range.
+/// `ord` receives the bound and returns where the value lies relative to it.
+#[inline]
+pub(crate) fn before_start<Q: ?Sized, F: FnOnce(&Q) -> Ordering>(start: Bound<&Q>, ord: F) -> bool {
+    return match start {
+        Bound::Included(bound) => ord(bound) == Ordering::Less,
+        Bound::Excluded(bound) => ord(bound) != Ordering::Greater,
+        Bound::Unbounded => false,
+    };
+}
+
+
+/// Returns a `bool` that determines if a value lies after the `end` bound of a range.
+/// `ord` receives the bound and returns where the value lies relative to it.
+#[inline]
+pub(crate) fn after_end<Q: ?Sized, F: FnOnce(&Q) -> Ordering>(end: Bound<&Q>, ord: F) -> bool {
+    return match end {
+        Bound::Included(bound) => ord(bound) == Ordering::Greater,
+        Bound::Excluded(bound) => ord(bound) != Ordering::Less,
+        Bound::Unbounded => false,
+    };
+}
+
+
+/// Walks the `Node`s of a Tree that lie within a range in ascending order, yielding pointers to them.
+/// Only the paths leading to the bounds of the range are descended, subtrees outside of it are never visited.
+/// 
+/// ## Fields:
+/// ```rust
+/// front: Vec<NonNull<Node<T>>> // Path to the next Node yielded from the front.
+/// back: Vec<NonNull<Node<T>>> // Path to the next Node yielded from the back.
+/// done: bool // Determines if both ends have met.
+/// ```
+pub(crate) struct RawRange<T> {
+    front: Vec<NonNull<Node<T>>>,
+    back: Vec<NonNull<Node<T>>>,
+    done: bool,
+}
+
+
+/// Struct for iterating over the values of a `BinaryTree` that lie within a range, in ascending order.
+pub struct Range<'a, T> {
+    raw: RawRange<T>,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+
+impl<T> RawRange<T> {
+    /// Constructs a walker over the `Node`s beneath `root` for which neither `before` nor `after` hold.
+    pub(crate) fn new<F, G>(root: Link<T>, mut before: F, mut after: G) -> Self
+    where
+        F: FnMut(&T) -> bool,
+        G: FnMut(&T) -> bool,
+    {
+        let (mut front, mut back) = (Vec::new(), Vec::new());
+
+        let mut current = root;
+        while let Some(ptr) = current {
+            let node = unsafe { ptr.as_ref() };
+            if before(&node.data) { current = node.right; }
+            else { front.push(ptr); current = node.left; }
+        }
+
+        let mut current = root;
+        while let Some(ptr) = current {
+            let node = unsafe { ptr.as_ref() };
+            if after(&node.data) { current = node.left; }
+            else { back.push(ptr); current = node.right; }
+        }
+
+        /* The range is empty if its smallest candidate already lies after the end. */
+        let done = match front.last() {
+            Some(ptr) => after(unsafe { &ptr.as_ref().data }),
+            None => true,
+        };
+
+        return Self { front, back, done };
+    }
+
+    /// Pushes `link` and its chain of left children onto the front stack.
+    fn push_left(&mut self, mut link: Link<T>) {
+        while let Some(ptr) = link {
+            self.front.push(ptr);
+            link = unsafe { ptr.as_ref().left };
+        }
+    }
+
+    /// Pushes `link` and its chain of right children onto the back stack.
+    fn push_right(&mut self, mut link: Link<T>) {
+        while let Some(ptr) = link {
+            self.back.push(ptr);
+            link = unsafe { ptr.as_ref().right };
+        }
+    }
+}
+
+
+impl<T> Iterator for RawRange<T> {
+    type Item = NonNull<Node<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done { return None; }
+
+        let ptr = self.front.pop()?;
+        if self.back.last() == Some(&ptr) { self.done = true; }
+        else { self.push_left(unsafe { ptr.as_ref().right }); }
+
+        return Some(ptr);
+    }
+}
+
+
+impl<T> DoubleEndedIterator for RawRange<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done { return None; }
+
+        let ptr = self.back.pop()?;
+        if self.front.last() == Some(&ptr) { self.done = true; }
+        else { self.push_right(unsafe { ptr.as_ref().left }); }
+
+        return Some(ptr);
+    }
+}
+
+
+impl<T> FusedIterator for RawRange<T> {  }
+
+
+impl<T> Clone for RawRange<T> {
+    fn clone(&self) -> Self {
+        return Self {
+            front: self.front.clone(),
+            back: self.back.clone(),
+            done: self.done,
+        };
+    }
+}
+
+
+impl<'a, T> Range<'a, T> {
+    pub(super) fn new(raw: RawRange<T>) -> Self {
+        return Self { raw, marker: PhantomData };
+    }
+}
+
+
+impl<'a, T> Iterator for Range<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.raw.next().map(|ptr| unsafe { &(*ptr.as_ptr()).data });
+    }
+}
+
+
+impl<'a, T> DoubleEndedIterator for Range<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.raw.next_back().map(|ptr| unsafe { &(*ptr.as_ptr()).data });
+    }
+}
+
+
+impl<'a, T> FusedIterator for Range<'a, T> {  }
+
+
+impl<'a, T> Clone for Range<'a, T> {
+    fn clone(&self) -> Self {
+        return Self { raw: self.raw.clone(), marker: PhantomData };
+    }
+}
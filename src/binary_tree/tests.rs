@@ -0,0 +1,562 @@
+use super::node::{Node, Link};
+use super::BinaryTree;
+
+use std::cmp::Ordering;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+
+/// Value that records its `id` into a shared log when dropped, ordered by its `id`.
+#[derive(Debug)]
+struct Droppable {
+    id: i32,
+    log: Rc<RefCell<Vec<i32>>>,
+}
+
+impl Droppable {
+    fn new(id: i32, log: &Rc<RefCell<Vec<i32>>>) -> Self {
+        return Self { id, log: Rc::clone(log) };
+    }
+}
+
+impl Drop for Droppable {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.id);
+    }
+}
+
+impl PartialEq for Droppable {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id;
+    }
+}
+
+impl PartialOrd for Droppable {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return self.id.partial_cmp(&other.id);
+    }
+}
+
+/// Returns the sorted `id`s recorded in `log`, so every drop can be checked to have happened exactly once.
+fn dropped(log: &Rc<RefCell<Vec<i32>>>) -> Vec<i32> {
+    let mut ids = log.borrow().clone();
+    ids.sort_unstable();
+    return ids;
+}
+
+
+/// Asserts the subtree beneath `link` is ordered, AVL balanced and has correct sizes, returning its height.
+fn check_balanced(link: Link<i32>) -> usize {
+    let node = match link {
+        Some(ptr) => unsafe { ptr.as_ref() },
+        None => return 0,
+    };
+
+    if let Some(left) = node.left { assert!(unsafe { left.as_ref() }.data < node.data); }
+    if let Some(right) = node.right { assert!(unsafe { right.as_ref() }.data > node.data); }
+
+    let (left, right) = (check_balanced(node.left), check_balanced(node.right));
+    assert!(left.max(right) - left.min(right) <= 1);
+    assert_eq!(node.height, 1 + left.max(right));
+    assert_eq!(node.size, 1 + Node::size(node.left) + Node::size(node.right));
+    return node.height;
+}
+
+
+#[test]
+fn default() {
+    let tree: BinaryTree<i32> = BinaryTree::default();
+    assert_eq!(tree.root, None);
+}
+
+#[test]
+fn insert() {
+    let mut tree = BinaryTree::new();
+    assert!(tree.insert(10));
+    assert!(tree.insert(15));
+    assert!(tree.insert(5));
+    assert!(tree.insert(20));
+    assert!(!tree.insert(15));
+
+    assert_eq!(tree.len(), 4);
+    assert!(tree.contains(&10));
+    assert!(tree.contains(&15));
+    assert!(tree.contains(&5));
+    assert!(tree.contains(&20));
+}
+
+#[test]
+fn clear() {
+    let mut tree = BinaryTree::new();
+    tree.insert("Swag");
+    tree.clear();
+    assert_eq!(tree, BinaryTree::new());
+}
+
+#[test]
+fn remove() {
+    let mut tree = BinaryTree::new();
+    for x in [50, 30, 70, 20, 40, 60, 80] { tree.insert(x); }
+
+    /* Leaf, single child and two children cases. */
+    assert!(tree.remove(&20));
+    assert!(tree.remove(&30));
+    assert!(tree.remove(&50));
+    assert!(!tree.remove(&50));
+    assert!(!tree.remove(&0));
+
+    assert_eq!(tree.pop_first(), Some(40));
+    assert_eq!(tree.pop_first(), Some(60));
+    assert_eq!(tree.pop_first(), Some(70));
+    assert_eq!(tree.pop_first(), Some(80));
+    assert_eq!(tree.pop_first(), None);
+}
+
+#[test]
+fn take() {
+    let mut tree = BinaryTree::new();
+    tree.insert("B");
+    tree.insert("A");
+    tree.insert("C");
+
+    assert_eq!(tree.take(&"B"), Some("B"));
+    assert_eq!(tree.take(&"B"), None);
+    assert_eq!(tree.take(&"A"), Some("A"));
+    assert_eq!(tree.take(&"C"), Some("C"));
+    assert_eq!(tree.root, None);
+}
+
+#[test]
+fn pop_first() {
+    let mut tree = BinaryTree::new();
+    for x in [3, 1, 4, 5, 2] { tree.insert(x); }
+
+    for x in 1 ..= 5 { assert_eq!(tree.pop_first(), Some(x)); }
+    assert_eq!(tree.pop_first(), None);
+}
+
+#[test]
+fn pop_last() {
+    let mut tree = BinaryTree::new();
+    for x in [3, 1, 4, 5, 2] { tree.insert(x); }
+
+    for x in (1 ..= 5).rev() { assert_eq!(tree.pop_last(), Some(x)); }
+    assert_eq!(tree.pop_last(), None);
+}
+
+#[test]
+fn length() {
+    let mut tree = BinaryTree::new();
+    assert!(tree.is_empty());
+
+    for x in [4, 2, 6, 2, 4] { tree.insert(x); }
+    assert_eq!(tree.len(), 3);
+
+    tree.remove(&2);
+    tree.remove(&2);
+    tree.pop_first();
+    tree.pop_last();
+    assert_eq!(tree.len(), 0);
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn contains() {
+    let mut tree = BinaryTree::new();
+    for x in ["M", "C", "X"] { tree.insert(x); }
+    assert!(tree.contains(&"C"));
+    assert!(!tree.contains(&"D"));
+}
+
+#[test]
+fn get() {
+    let mut tree = BinaryTree::new();
+    for x in [8, 3, 10, 1, 6] { tree.insert(x); }
+    assert_eq!(tree.get(&6), Some(&6));
+    assert_eq!(tree.get(&7), None);
+}
+
+#[test]
+fn first_last() {
+    let mut tree = BinaryTree::new();
+    assert_eq!(tree.first(), None);
+    assert_eq!(tree.last(), None);
+
+    for x in [8, 3, 10, 1, 6, 14] { tree.insert(x); }
+    assert_eq!(tree.first(), Some(&1));
+    assert_eq!(tree.last(), Some(&14));
+}
+
+#[test]
+fn floor_ceiling() {
+    let mut tree = BinaryTree::new();
+    for x in [40, 20, 60, 10, 30, 50, 70] { tree.insert(x); }
+
+    assert_eq!(tree.floor(&35), Some(&30));
+    assert_eq!(tree.floor(&30), Some(&30));
+    assert_eq!(tree.floor(&5), None);
+    assert_eq!(tree.floor(&100), Some(&70));
+
+    assert_eq!(tree.ceiling(&35), Some(&40));
+    assert_eq!(tree.ceiling(&40), Some(&40));
+    assert_eq!(tree.ceiling(&75), None);
+    assert_eq!(tree.ceiling(&0), Some(&10));
+}
+
+#[test]
+fn predecessor_successor() {
+    let mut tree = BinaryTree::new();
+    for x in [40, 20, 60, 10, 30, 50, 70] { tree.insert(x); }
+
+    assert_eq!(tree.predecessor(&40), Some(&30));
+    assert_eq!(tree.predecessor(&35), Some(&30));
+    assert_eq!(tree.predecessor(&10), None);
+
+    assert_eq!(tree.successor(&40), Some(&50));
+    assert_eq!(tree.successor(&45), Some(&50));
+    assert_eq!(tree.successor(&70), None);
+}
+
+fn sample() -> BinaryTree<i32> {
+    /*
+              40
+           /      \
+         20        60
+        /  \      /  \
+      10    30  50    70
+    */
+    let mut tree = BinaryTree::new();
+    for x in [40, 20, 60, 10, 30, 50, 70] { tree.insert(x); }
+    return tree;
+}
+
+#[test]
+fn iter() {
+    let tree = sample();
+    assert_eq!(tree.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30, 40, 50, 60, 70]);
+    assert_eq!(tree.iter().rev().copied().collect::<Vec<_>>(), vec![70, 60, 50, 40, 30, 20, 10]);
+    assert_eq!(BinaryTree::<i32>::new().iter().next(), None);
+}
+
+#[test]
+fn iter_double_ended() {
+    let tree = sample();
+    let mut iter = tree.iter();
+
+    assert_eq!(iter.len(), 7);
+    assert_eq!(iter.next(), Some(&10));
+    assert_eq!(iter.next_back(), Some(&70));
+    assert_eq!(iter.next_back(), Some(&60));
+    assert_eq!(iter.next(), Some(&20));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(&30));
+    assert_eq!(iter.next_back(), Some(&50));
+    assert_eq!(iter.next(), Some(&40));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn pre_order() {
+    let tree = sample();
+    assert_eq!(tree.pre_order().copied().collect::<Vec<_>>(), vec![40, 20, 10, 30, 60, 50, 70]);
+}
+
+#[test]
+fn post_order() {
+    let tree = sample();
+    assert_eq!(tree.post_order().copied().collect::<Vec<_>>(), vec![10, 30, 20, 50, 70, 60, 40]);
+}
+
+#[test]
+fn level_order() {
+    let tree = sample();
+    assert_eq!(tree.level_order().len(), 7);
+    assert_eq!(tree.level_order().copied().collect::<Vec<_>>(), vec![40, 20, 60, 10, 30, 50, 70]);
+}
+
+#[test]
+fn into_iter() {
+    assert_eq!(sample().into_iter().collect::<Vec<_>>(), vec![10, 20, 30, 40, 50, 60, 70]);
+
+    let mut iter = sample().into_iter();
+    assert_eq!(iter.next_back(), Some(70));
+    assert_eq!(iter.next(), Some(10));
+    assert_eq!(iter.len(), 5);
+
+    let mut sum = 0;
+    for x in &sample() { sum += x; }
+    assert_eq!(sum, 280);
+}
+
+#[test]
+fn sorted_insert_traversal() {
+    const N: usize = if cfg!(miri) { 200 } else { 2_000 };
+
+    let mut tree = BinaryTree::new();
+    for x in 0 .. N { tree.insert(x); }
+
+    /* Sorted input would degenerate into a list without rebalancing. */
+    assert!(Node::height(tree.root) <= (1.45 * ((N + 2) as f64).log2()) as usize);
+
+    assert_eq!(tree.iter().count(), N);
+    assert_eq!(tree.pre_order().count(), N);
+    assert_eq!(tree.post_order().count(), N);
+    assert_eq!(tree.level_order().count(), N);
+    assert!(tree.into_iter().eq(0 .. N));
+}
+
+#[test]
+fn balanced_sorted_insert() {
+    const N: usize = if cfg!(miri) { 1_000 } else { 1_000_000 };
+
+    let mut tree = BinaryTree::new();
+    for x in 0 .. N { tree.insert(x); }
+
+    /* An AVL Tree's height is bounded by roughly `1.44 * log2(n + 2)`. */
+    let bound = (1.45 * ((N + 2) as f64).log2()) as usize;
+    assert!(Node::height(tree.root) <= bound);
+    assert_eq!(tree.len(), N);
+    assert_eq!(tree.first(), Some(&0));
+    assert_eq!(tree.last(), Some(&(N - 1)));
+}
+
+#[test]
+fn balanced_insert_remove() {
+    const N: i32 = if cfg!(miri) { 200 } else { 1_000 };
+
+    let mut tree = BinaryTree::new();
+    for x in 0 .. N { tree.insert(x); }
+    check_balanced(tree.root);
+
+    for x in (0 .. N).step_by(3) { assert!(tree.remove(&x)); }
+    check_balanced(tree.root);
+
+    for x in (N .. 2 * N).rev() { tree.insert(x); }
+    check_balanced(tree.root);
+
+    for _ in 0 .. N / 5 { tree.pop_first(); tree.pop_last(); }
+    check_balanced(tree.root);
+    assert!(tree.iter().zip(tree.iter().skip(1)).all(|(a, b)| a < b));
+}
+
+#[test]
+fn range() {
+    let tree = sample();
+    assert_eq!(tree.range(20 .. 50).copied().collect::<Vec<_>>(), vec![20, 30, 40]);
+    assert_eq!(tree.range(15 ..= 50).copied().collect::<Vec<_>>(), vec![20, 30, 40, 50]);
+    assert_eq!(tree.range(.. 30).copied().collect::<Vec<_>>(), vec![10, 20]);
+    assert_eq!(tree.range(55 ..).copied().collect::<Vec<_>>(), vec![60, 70]);
+    assert_eq!(tree.range(..).count(), 7);
+    assert_eq!(tree.range(31 .. 39).next(), None);
+    assert_eq!(tree.range(100 ..).next_back(), None);
+}
+
+#[test]
+fn range_bounds() {
+    use std::collections::BTreeSet;
+    use std::ops::Bound::{self, Included, Excluded, Unbounded};
+
+    const N: i32 = if cfg!(miri) { 10 } else { 50 };
+
+    let mut tree = BinaryTree::new();
+    let mut set = BTreeSet::new();
+    for x in (0 .. N).map(|x| (x * 7) % N).filter(|x| x % 3 != 0) { tree.insert(x); set.insert(x); }
+
+    let bounds = |x: i32| -> [Bound<i32>; 3] { [Included(x), Excluded(x), Unbounded] };
+
+    for start in -1 .. N + 2 {
+        for end in start + 1 .. N + 2 {
+            for &s in bounds(start).iter() {
+                for &e in bounds(end).iter() {
+                    let expected: Vec<_> = set.range((s, e)).collect();
+                    assert_eq!(tree.range((s, e)).collect::<Vec<_>>(), expected);
+
+                    let reversed: Vec<_> = set.range((s, e)).rev().collect();
+                    assert_eq!(tree.range((s, e)).rev().collect::<Vec<_>>(), reversed);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn range_double_ended() {
+    let tree = sample();
+    let mut range = tree.range(15 .. 65);
+
+    assert_eq!(range.next(), Some(&20));
+    assert_eq!(range.next_back(), Some(&60));
+    assert_eq!(range.next_back(), Some(&50));
+    assert_eq!(range.next(), Some(&30));
+    assert_eq!(range.next(), Some(&40));
+    assert_eq!(range.next_back(), None);
+    assert_eq!(range.next(), None);
+}
+
+#[test]
+fn drain_range() {
+    let mut tree = sample();
+    assert_eq!(tree.drain_range(20 ..= 50).collect::<Vec<_>>(), vec![20, 30, 40, 50]);
+    assert_eq!(tree.iter().copied().collect::<Vec<_>>(), vec![10, 60, 70]);
+    assert_eq!(tree.len(), 3);
+
+    assert_eq!(tree.drain_range(.. 0).count(), 0);
+    assert_eq!(tree.len(), 3);
+
+    tree.drain_range(..);
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn split_off() {
+    let mut tree = BinaryTree::new();
+    for x in 0 .. 100 { tree.insert(x); }
+
+    let upper = tree.split_off(&60);
+    assert!(tree.iter().copied().eq(0 .. 60));
+    assert!(upper.iter().copied().eq(60 .. 100));
+    assert_eq!((tree.len(), upper.len()), (60, 40));
+    check_balanced(tree.root);
+    check_balanced(upper.root);
+
+    /* The split off values are rebuilt into a Tree of minimal height. */
+    for n in 0 .. 70 {
+        let mut tree = BinaryTree::new();
+        for x in 0 .. n { tree.insert(x); }
+
+        let all = tree.split_off(&0);
+        check_balanced(all.root);
+        assert_eq!(Node::height(all.root), (usize::BITS - (n as usize).leading_zeros()) as usize);
+        assert!(all.into_iter().eq(0 .. n));
+    }
+
+    let mut tree = sample();
+    assert!(tree.split_off(&100).is_empty());
+    assert_eq!(tree.split_off(&0).len(), 7);
+    assert!(tree.is_empty());
+}
+
+#[test]
+fn rank() {
+    let tree = sample();
+    assert_eq!(tree.rank(&10), 0);
+    assert_eq!(tree.rank(&40), 3);
+    assert_eq!(tree.rank(&45), 4);
+    assert_eq!(tree.rank(&70), 6);
+    assert_eq!(tree.rank(&100), 7);
+    assert_eq!(BinaryTree::new().rank(&1), 0);
+}
+
+#[test]
+fn select() {
+    let tree = sample();
+    for (i, x) in tree.iter().enumerate() { assert_eq!(tree.select(i), Some(x)); }
+    assert_eq!(tree.select(7), None);
+}
+
+#[test]
+fn order_statistics_after_updates() {
+    let mut tree = BinaryTree::new();
+    for x in (0 .. 500).rev() { tree.insert(x * 2); }
+    for x in (0 .. 1_000).step_by(6) { tree.remove(&x); }
+    tree.pop_first();
+    tree.pop_last();
+    let upper = tree.split_off(&700);
+    check_balanced(tree.root);
+    check_balanced(upper.root);
+
+    let values: Vec<i32> = tree.iter().copied().collect();
+    for (i, x) in values.iter().enumerate() {
+        assert_eq!(tree.select(i), Some(x));
+        assert_eq!(tree.rank(x), i);
+        assert_eq!(tree.rank(&(x + 1)), i + 1);
+    }
+
+    assert_eq!(upper.select(0), Some(&700));
+    assert_eq!(upper.rank(&701), 1);
+}
+
+#[test]
+fn drop_frees_every_value_once() {
+    const N: i32 = if cfg!(miri) { 200 } else { 1_000 };
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut tree = BinaryTree::new();
+    for x in 0 .. N { tree.insert(Droppable::new(x, &log)); }
+    assert!(log.borrow().is_empty());
+
+    drop(tree);
+    assert_eq!(dropped(&log), (0 .. N).collect::<Vec<_>>());
+}
+
+#[test]
+fn drop_rejected_duplicate() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut tree = BinaryTree::new();
+    assert!(tree.insert(Droppable::new(1, &log)));
+    assert!(!tree.insert(Droppable::new(1, &log)));
+    assert_eq!(dropped(&log), vec![1]);
+
+    drop(tree);
+    assert_eq!(dropped(&log), vec![1, 1]);
+}
+
+#[test]
+fn clear_frees_every_value_once() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut tree = BinaryTree::new();
+    for x in 0 .. 100 { tree.insert(Droppable::new(x, &log)); }
+    tree.clear();
+    assert_eq!(dropped(&log), (0 .. 100).collect::<Vec<_>>());
+    assert!(tree.is_empty());
+
+    tree.insert(Droppable::new(100, &log));
+    drop(tree);
+    assert_eq!(dropped(&log), (0 ..= 100).collect::<Vec<_>>());
+}
+
+#[test]
+fn removal_drops_every_value_once() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut tree = BinaryTree::new();
+    for x in 0 .. 20 { tree.insert(Droppable::new(x, &log)); }
+
+    assert!(tree.remove(&Droppable::new(5, &log)));
+    drop(tree.take(&Droppable::new(6, &log)));
+    drop(tree.pop_first());
+    drop(tree.pop_last());
+    drop(tree.split_off(&Droppable::new(15, &log)));
+    drop(tree.drain_range(Droppable::new(10, &log) ..));
+
+    /* Every probe value used for searching is dropped as well. */
+    let mut expected: Vec<i32> = (0 .. 20).filter(|x| [0, 5, 6].contains(x) || *x >= 10).collect();
+    expected.extend(vec![5, 6, 10, 15]);
+    expected.sort_unstable();
+    assert_eq!(dropped(&log), expected);
+
+    drop(tree);
+    let mut expected: Vec<i32> = (0 .. 20).collect();
+    expected.extend(vec![5, 6, 10, 15]);
+    expected.sort_unstable();
+    assert_eq!(dropped(&log), expected);
+}
+
+#[test]
+fn into_iter_drops_remaining_values() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut tree = BinaryTree::new();
+    for x in 0 .. 10 { tree.insert(Droppable::new(x, &log)); }
+
+    let mut iter = tree.into_iter();
+    assert_eq!(iter.next().map(|e| e.id), Some(0));
+    assert_eq!(iter.next_back().map(|e| e.id), Some(9));
+    assert_eq!(dropped(&log), vec![0, 9]);
+
+    drop(iter);
+    assert_eq!(dropped(&log), (0 .. 10).collect::<Vec<_>>());
+}
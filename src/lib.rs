@@ -1,12 +1,103 @@
-#[allow(dead_code)]
-pub(crate) mod binary_tree;
-#[allow(dead_code)]
-pub(crate) mod linked_list;
-#[allow(dead_code)]
-pub(crate) mod vector;
+//! Library implementing various Data Structures in Rust.
+//!
+//! Every container is re-exported at the top level of the crate, alongside its shorthand macro and iterator types.
+//! Iterator types are prefixed with the name of their container at the top level, as most containers share names like `Iter`.
+//! ```rust
+//! use rust_data_structures::prelude::*;
+//!
+//! let list: LinkedList<i32> = list![1, 2, 3];
+//! let vec: Vector<i32> = vector![1, 2, 3];
+//! ```
+//!
+//! The crate is `no_std` and only depends on `alloc` once the default `std` feature is disabled,
+//! which only adds `std::error::Error` impls.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![allow(clippy::needless_return)]
+
+extern crate alloc;
+
+pub mod array_vector;
+pub mod binary_tree;
+pub mod linked_list;
+pub mod small_vector;
+pub mod tree_map;
+pub mod vector;
+
+pub use array_vector::ArrayVector;
+pub use binary_tree::BinaryTree;
+pub use linked_list::LinkedList;
+pub use small_vector::SmallVector;
+pub use tree_map::TreeMap;
+pub use vector::Vector;
+
+pub use array_vector::{
+    Iter as ArrayVectorIter, IterMut as ArrayVectorIterMut, IntoIter as ArrayVectorIntoIter,
+};
+pub use binary_tree::{
+    Iter as BinaryTreeIter, PreOrder as BinaryTreePreOrder, PostOrder as BinaryTreePostOrder,
+    LevelOrder as BinaryTreeLevelOrder, Range as BinaryTreeRange, IntoIter as BinaryTreeIntoIter,
+};
+pub use linked_list::{
+    Iter as LinkedListIter, IterMut as LinkedListIterMut, IntoIter as LinkedListIntoIter,
+    ExtractIf as LinkedListExtractIf,
+};
+pub use small_vector::{
+    Iter as SmallVectorIter, IterMut as SmallVectorIterMut, IntoIter as SmallVectorIntoIter,
+};
+pub use tree_map::{
+    Iter as TreeMapIter, IterMut as TreeMapIterMut, Keys as TreeMapKeys, Values as TreeMapValues,
+    ValuesMut as TreeMapValuesMut, Range as TreeMapRange, RangeMut as TreeMapRangeMut, IntoIter as TreeMapIntoIter,
+};
+pub use vector::{
+    Iter as VectorIter, IterMut as VectorIterMut, IntoIter as VectorIntoIter, Drain as VectorDrain,
+};
+
+
+/// Commonly used containers and macros, intended to be glob imported.
+/// ## Example:
+/// ```rust
+/// use rust_data_structures::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::{ArrayVector, BinaryTree, LinkedList, SmallVector, TreeMap, Vector};
+    pub use crate::{array_vector, list, small_vector, vector};
+}
 
 
 #[cfg(test)]
 mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn prelude() {
+        let list: LinkedList<i32> = list![1, 2, 3];
+        assert_eq!(list.len(), 3);
+
+        let vec: Vector<i32> = vector![1, 2, 3];
+        assert_eq!(vec.len(), 3);
+
+        let small: SmallVector<i32, 4> = small_vector![1, 2, 3];
+        assert_eq!(small.len(), 3);
+
+        let array: ArrayVector<i32, 4> = array_vector![1, 2, 3];
+        assert_eq!(array.len(), 3);
+
+        let mut tree: BinaryTree<i32> = BinaryTree::new();
+        tree.insert(1);
+    }
+
+    #[test]
+    fn iterator_reexports() {
+        let list: crate::LinkedList<i32> = list![1, 2, 3];
+        let iter: crate::LinkedListIter<'_, i32> = list.iter();
+        assert_eq!(iter.len(), 3);
+
+        let vec: crate::Vector<i32> = vector![1, 2, 3];
+        let into_iter: crate::VectorIntoIter<i32> = vec.into_iter();
+        assert_eq!(into_iter.len(), 3);
 
+        let array: crate::ArrayVector<i32, 4> = array_vector![1, 2];
+        let into_iter: crate::ArrayVectorIntoIter<i32, 4> = array.into_iter();
+        assert_eq!(into_iter.len(), 2);
+    }
 }
@@ -0,0 +1,290 @@
+use super::LinkedList;
+use super::node::Link;
+
+use core::option::Option;
+
+
+/// Struct pointing at a position within a `LinkedList`, created by `LinkedList::cursor_front` or `LinkedList::cursor_back`.
+/// Besides the values of the List the `Cursor` can point at a "ghost" position sitting between the back and the front,
+/// moving past either end of the List lands on it and moving once more wraps around to the other end.
+///
+/// ## Fields:
+/// ```rust
+/// index: usize // Index of the current Node, the length of the List at the ghost position.
+/// current: Option<NonNull<Node<T>>> // Node the Cursor points at, None at the ghost position.
+/// list: &'a LinkedList<T> // List the Cursor walks over.
+/// ```
+pub struct Cursor<'a, T> {
+    index: usize,
+    current: Link<T>,
+    list: &'a LinkedList<T>,
+}
+
+
+/// Struct pointing at a position within a `LinkedList` able to edit the List around it in `O(1)`,
+/// created by `LinkedList::cursor_front_mut` or `LinkedList::cursor_back_mut`.
+/// Moves around the same way as a `Cursor`, including the "ghost" position between the back and the front.
+///
+/// ## Fields:
+/// ```rust
+/// index: usize // Index of the current Node, the length of the List at the ghost position.
+/// current: Option<NonNull<Node<T>>> // Node the Cursor points at, None at the ghost position.
+/// list: &'a mut LinkedList<T> // List the Cursor walks over and edits.
+/// ```
+pub struct CursorMut<'a, T> {
+    index: usize,
+    current: Link<T>,
+    list: &'a mut LinkedList<T>,
+}
+
+
+impl<'a, T> Cursor<'a, T> {
+    pub(super) fn new(list: &'a LinkedList<T>, current: Link<T>, index: usize) -> Self {
+        return Self { index, current, list };
+    }
+
+    /// Returns the index of the value the `Cursor` points at, or `None` at the ghost position.
+    #[inline(always)]
+    pub fn index(&self) -> Option<usize> {
+        return self.current.map(|_| self.index);
+    }
+
+    /// Moves the `Cursor` to the next value, the ghost position following the back of the `LinkedList`.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(ptr) => unsafe {
+                self.current = ptr.as_ref().next;
+                self.index += 1;
+            },
+
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            },
+        }
+    }
+
+    /// Moves the `Cursor` to the previous value, the ghost position preceding the front of the `LinkedList`.
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(ptr) => unsafe {
+                self.current = ptr.as_ref().previous;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.length);
+            },
+
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.length.saturating_sub(1);
+            },
+        }
+    }
+
+    /// Returns a reference to the value the `Cursor` points at, or `None` at the ghost position.
+    #[inline(always)]
+    pub fn current(&self) -> Option<&'a T> {
+        return self.current.map(|ptr| unsafe { &(*ptr.as_ptr()).data });
+    }
+
+    /// Returns a reference to the value after the one the `Cursor` points at,
+    /// which is the front of the `LinkedList` at the ghost position.
+    pub fn peek_next(&self) -> Option<&'a T> {
+        let next = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().next },
+            None => self.list.head,
+        };
+
+        return next.map(|ptr| unsafe { &(*ptr.as_ptr()).data });
+    }
+
+    /// Returns a reference to the value before the one the `Cursor` points at,
+    /// which is the back of the `LinkedList` at the ghost position.
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        let previous = match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().previous },
+            None => self.list.tail,
+        };
+
+        return previous.map(|ptr| unsafe { &(*ptr.as_ptr()).data });
+    }
+}
+
+
+impl<'a, T> CursorMut<'a, T> {
+    pub(super) fn new(list: &'a mut LinkedList<T>, current: Link<T>, index: usize) -> Self {
+        return Self { index, current, list };
+    }
+
+    /// Returns the index of the value the `CursorMut` points at, or `None` at the ghost position.
+    #[inline(always)]
+    pub fn index(&self) -> Option<usize> {
+        return self.current.map(|_| self.index);
+    }
+
+    /// Moves the `CursorMut` to the next value, the ghost position following the back of the `LinkedList`.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(ptr) => unsafe {
+                self.current = ptr.as_ref().next;
+                self.index += 1;
+            },
+
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            },
+        }
+    }
+
+    /// Moves the `CursorMut` to the previous value, the ghost position preceding the front of the `LinkedList`.
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(ptr) => unsafe {
+                self.current = ptr.as_ref().previous;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.length);
+            },
+
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.length.saturating_sub(1);
+            },
+        }
+    }
+
+    /// Returns a mutable reference to the value the `CursorMut` points at, or `None` at the ghost position.
+    #[inline(always)]
+    pub fn current(&mut self) -> Option<&mut T> {
+        return self.current.map(|ptr| unsafe { &mut (*ptr.as_ptr()).data });
+    }
+
+    /// Returns a mutable reference to the value after the one the `CursorMut` points at,
+    /// which is the front of the `LinkedList` at the ghost position.
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        return self.next_node().map(|ptr| unsafe { &mut (*ptr.as_ptr()).data });
+    }
+
+    /// Returns a mutable reference to the value before the one the `CursorMut` points at,
+    /// which is the back of the `LinkedList` at the ghost position.
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        return self.previous_node().map(|ptr| unsafe { &mut (*ptr.as_ptr()).data });
+    }
+
+    /// Returns a read-only `Cursor` pointing at the same position, borrowing the `CursorMut` while it lives.
+    #[inline(always)]
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        return Cursor::new(self.list, self.current, self.index);
+    }
+
+    /// Inserts `data` after the value the `CursorMut` points at, or at the front of the `LinkedList` at the ghost position.
+    ///
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.insert_after(2);
+    /// assert_eq!(cursor.current(), Some(&mut 1));
+    /// assert_eq!(list, list![1, 2, 3]);
+    /// ```
+    pub fn insert_after(&mut self, data: T) {
+        unsafe { self.list.link_between(data, self.current, self.next_node()); }
+        if self.current.is_none() { self.index = self.list.length; }
+    }
+
+    /// Inserts `data` before the value the `CursorMut` points at, or at the back of the `LinkedList` at the ghost position.
+    pub fn insert_before(&mut self, data: T) {
+        unsafe { self.list.link_between(data, self.previous_node(), self.current); }
+        self.index += 1;
+    }
+
+    /// Removes the value the `CursorMut` points at and returns it, moving the `CursorMut` to the next value.
+    /// Does nothing and returns `None` at the ghost position.
+    ///
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// assert_eq!(cursor.remove_current(), Some(1));
+    /// assert_eq!(cursor.current(), Some(&mut 2));
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = unsafe { self.list.unlink_node(self.current?) };
+        self.current = node.next;
+        return Some(node.data);
+    }
+
+    /// Splits the `LinkedList` after the value the `CursorMut` points at, returning every value after it as a new `LinkedList`.
+    /// At the ghost position the entire `LinkedList` is split off.
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        let at = match self.current {
+            Some(_) => self.index + 1,
+            None => 0,
+        };
+
+        let split = unsafe { self.list.split_after_node(self.current, at) };
+        if self.current.is_none() { self.index = 0; }
+        return split;
+    }
+
+    /// Splits the `LinkedList` before the value the `CursorMut` points at, returning every value before it as a new `LinkedList`.
+    /// At the ghost position the entire `LinkedList` is split off.
+    pub fn split_before(&mut self) -> LinkedList<T> {
+        let at = match self.current {
+            Some(_) => self.index,
+            None => self.list.length,
+        };
+
+        let split = unsafe { self.list.split_before_node(self.current, at) };
+        self.index = 0;
+        return split;
+    }
+
+    /// Moves every value of `other` after the value the `CursorMut` points at, leaving `other` empty.
+    /// At the ghost position the values are moved to the front of the `LinkedList`.
+    ///
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 4];
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.splice_after(list![2, 3]);
+    /// assert_eq!(list, list![1, 2, 3, 4]);
+    /// ```
+    pub fn splice_after(&mut self, mut other: LinkedList<T>) {
+        unsafe { self.list.splice_between(self.current, self.next_node(), &mut other); }
+        if self.current.is_none() { self.index = self.list.length; }
+    }
+
+    /// Moves every value of `other` before the value the `CursorMut` points at, leaving `other` empty.
+    /// At the ghost position the values are moved to the back of the `LinkedList`.
+    pub fn splice_before(&mut self, mut other: LinkedList<T>) {
+        self.index += other.length;
+        unsafe { self.list.splice_between(self.previous_node(), self.current, &mut other); }
+    }
+}
+
+
+/* Private Methods */
+impl<'a, T> CursorMut<'a, T> {
+    /// Returns the `Node` after the current one, the head of the `LinkedList` at the ghost position.
+    #[inline(always)]
+    fn next_node(&self) -> Link<T> {
+        return match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().next },
+            None => self.list.head,
+        };
+    }
+
+    /// Returns the `Node` before the current one, the tail of the `LinkedList` at the ghost position.
+    #[inline(always)]
+    fn previous_node(&self) -> Link<T> {
+        return match self.current {
+            Some(ptr) => unsafe { ptr.as_ref().previous },
+            None => self.list.tail,
+        };
+    }
+}
+
+
+impl<'a, T> Clone for Cursor<'a, T> {
+    fn clone(&self) -> Self {
+        return Self { ..*self };
+    }
+}
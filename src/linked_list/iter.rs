@@ -0,0 +1,235 @@
+use super::LinkedList;
+use super::node::{Node, Link};
+
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::option::Option;
+
+
+/// Struct for iterating over references to the values of a `LinkedList`, created by `LinkedList::iter`.
+/// The front walks along `next` pointers while the back walks along `previous` pointers,
+/// `length` counts the values between them so the two ends never cross.
+///
+/// ## Fields:
+/// ```rust
+/// head: Option<NonNull<Node<T>>> // Next Node yielded from the front.
+/// tail: Option<NonNull<Node<T>>> // Next Node yielded from the back.
+/// length: usize // Amount of values that have not been yielded yet.
+/// ```
+pub struct Iter<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    length: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+
+/// Struct for iterating over mutable references to the values of a `LinkedList`, created by `LinkedList::iter_mut`.
+///
+/// ## Fields:
+/// ```rust
+/// head: Option<NonNull<Node<T>>> // Next Node yielded from the front.
+/// tail: Option<NonNull<Node<T>>> // Next Node yielded from the back.
+/// length: usize // Amount of values that have not been yielded yet.
+/// ```
+pub struct IterMut<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    length: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+
+/// Struct for converting a `LinkedList` into an `Iterator`.
+/// The `Iterator` is based off continuously popping the List's head and returning its data vale.
+///
+/// ## Implementation:
+/// ```rust
+/// impl<T> Iterator for IntoIter<T> {
+///    type Item = T;
+///
+///    #[inline(always)]
+///    fn next(&mut self) -> Option<T> {
+///        return self.list.pop_front();
+///    }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+}
+
+
+/// Struct for unlinking the values of a `LinkedList` that match a filter, created by `LinkedList::extract_if`.
+///
+/// ## Fields:
+/// ```rust
+/// list: &'a mut LinkedList<T> // List the values are unlinked from.
+/// current: Option<NonNull<Node<T>>> // Next Node passed to the filter.
+/// filter: F // Closure deciding which values are unlinked.
+/// ```
+pub struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool> {
+    list: &'a mut LinkedList<T>,
+    current: Link<T>,
+    filter: F,
+}
+
+
+impl<'a, T> Iter<'a, T> {
+    pub(super) fn new(head: Link<T>, tail: Link<T>, length: usize) -> Self {
+        return Self { head, tail, length, marker: PhantomData };
+    }
+}
+
+
+impl<'a, T> IterMut<'a, T> {
+    pub(super) fn new(head: Link<T>, tail: Link<T>, length: usize) -> Self {
+        return Self { head, tail, length, marker: PhantomData };
+    }
+}
+
+
+impl<T> IntoIter<T> {
+    pub(super) fn new(list: LinkedList<T>) -> Self {
+        return Self { list };
+    }
+}
+
+
+impl<'a, T, F: FnMut(&mut T) -> bool> ExtractIf<'a, T, F> {
+    pub(super) fn new(list: &'a mut LinkedList<T>, filter: F) -> Self {
+        let current = list.head;
+        return Self { list, current, filter };
+    }
+}
+
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 { return None; }
+
+        let node = unsafe { &*self.head?.as_ptr() };
+        self.head = node.next;
+        self.length -= 1;
+        return Some(&node.data);
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.length, Some(self.length));
+    }
+}
+
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 { return None; }
+
+        let node = unsafe { &*self.tail?.as_ptr() };
+        self.tail = node.previous;
+        self.length -= 1;
+        return Some(&node.data);
+    }
+}
+
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {  }
+impl<'a, T> FusedIterator for Iter<'a, T> {  }
+
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        return Self { ..*self };
+    }
+}
+
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 { return None; }
+
+        let node = unsafe { &mut *self.head?.as_ptr() };
+        self.head = node.next;
+        self.length -= 1;
+        return Some(&mut node.data);
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.length, Some(self.length));
+    }
+}
+
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 { return None; }
+
+        let node = unsafe { &mut *self.tail?.as_ptr() };
+        self.tail = node.previous;
+        self.length -= 1;
+        return Some(&mut node.data);
+    }
+}
+
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {  }
+impl<'a, T> FusedIterator for IterMut<'a, T> {  }
+
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<T> {
+        return self.list.pop_front();
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (self.list.length, Some(self.list.length));
+    }
+}
+
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.list.pop_back();
+    }
+}
+
+
+impl<T> ExactSizeIterator for IntoIter<T> {  }
+impl<T> FusedIterator for IntoIter<T> {  }
+
+
+impl<'a, T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'a, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(ptr) = self.current {
+            unsafe {
+                self.current = ptr.as_ref().next;
+
+                if (self.filter)(&mut (*ptr.as_ptr()).data) {
+                    return Some(self.list.unlink_node(ptr).data);
+                }
+            }
+        }
+
+        return None;
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return (0, Some(self.list.length));
+    }
+}
+
+
+impl<'a, T, F: FnMut(&mut T) -> bool> FusedIterator for ExtractIf<'a, T, F> {  }
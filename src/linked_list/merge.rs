@@ -0,0 +1,143 @@
+use super::LinkedList;
+use super::node::{Node, Link};
+
+use core::mem::replace as mem_replace;
+use core::cmp::Ordering;
+use core::ptr::NonNull;
+
+
+/// Struct holding every `Node` of a `LinkedList` while `sort_by` or `merge` relinks them through `next` pointers alone.
+/// Dropping it joins whatever chains are left back into the `LinkedList` and rebuilds its `previous` pointers,
+/// so the List stays consistent even if the comparator panics halfway through.
+///
+/// ## Fields:
+/// ```rust
+/// list: &'a mut LinkedList<T> // List the Nodes are handed back to.
+/// head: Option<NonNull<Node<T>>> // First Node of the merged chain.
+/// tail: Option<NonNull<Node<T>>> // Last Node of the merged chain.
+/// left: Option<NonNull<Node<T>>> // Run currently being merged, ties are taken from it first.
+/// right: Option<NonNull<Node<T>>> // Run currently being merged after `left`.
+/// remaining: Option<NonNull<Node<T>>> // Nodes not yet reached during the current pass.
+/// length: usize // Amount of Nodes held across every chain.
+/// ```
+pub(super) struct MergeGuard<'a, T> {
+    list: &'a mut LinkedList<T>,
+    head: Link<T>,
+    tail: Link<T>,
+    left: Link<T>,
+    right: Link<T>,
+    remaining: Link<T>,
+    length: usize,
+}
+
+
+impl<'a, T> MergeGuard<'a, T> {
+    /// Takes every `Node` of `list` as the merged chain, leaving `list` empty until the `MergeGuard` is dropped.
+    pub(super) fn new(list: &'a mut LinkedList<T>) -> Self {
+        let head = list.head.take();
+        let tail = list.tail.take();
+        let length = mem_replace(&mut list.length, 0);
+
+        return Self { list, head, tail, left: None, right: None, remaining: None, length };
+    }
+
+    /// Moves the merged chain back into `remaining` to start another pass over it.
+    pub(super) fn restart(&mut self) {
+        self.remaining = self.head.take();
+        self.tail = None;
+    }
+
+    /// Moves the merged chain into `left` and every `Node` of `other` into `right`, leaving `other` empty.
+    pub(super) fn take_other(&mut self, other: &mut LinkedList<T>) {
+        self.left = self.head.take();
+        self.tail = None;
+
+        self.right = other.head.take();
+        other.tail = None;
+        self.length += mem_replace(&mut other.length, 0);
+    }
+
+    /// Cuts the next two runs of up to `width` Nodes off `remaining` into `left` and `right`,
+    /// returning `false` once `remaining` is empty.
+    pub(super) fn next_runs(&mut self, width: usize) -> bool {
+        self.left = self.remaining.take();
+
+        unsafe {
+            self.right = self.left.and_then(|ptr| cut_chain(ptr, width));
+            self.remaining = self.right.and_then(|ptr| cut_chain(ptr, width));
+        }
+
+        return self.left.is_some();
+    }
+
+    /// Merges `left` and `right` onto the back of the merged chain, taking ties from `left` first so the merge is stable.
+    pub(super) fn merge_runs<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: &mut F) {
+        unsafe {
+            while let (Some(l), Some(r)) = (self.left, self.right) {
+                if compare(&r.as_ref().data, &l.as_ref().data) == Ordering::Less {
+                    self.right = r.as_ref().next;
+                    self.push(r);
+                } else {
+                    self.left = l.as_ref().next;
+                    self.push(l);
+                }
+            }
+
+            let rest = self.left.take().or_else(|| self.right.take());
+            self.append(rest);
+        }
+    }
+}
+
+
+/* Private Methods */
+impl<'a, T> MergeGuard<'a, T> {
+    /// Links `node` after the merged chain, its `next` pointer is left as is until something else is linked after it.
+    unsafe fn push(&mut self, node: NonNull<Node<T>>) {
+        match self.tail {
+            Some(mut ptr) => ptr.as_mut().next = Some(node),
+            None => self.head = Some(node),
+        }
+
+        self.tail = Some(node);
+    }
+
+    /// Links the entire `chain` after the merged chain, walking it to find its last `Node`.
+    unsafe fn append(&mut self, chain: Link<T>) {
+        let mut last = match chain {
+            Some(ptr) => ptr,
+            None => return,
+        };
+
+        self.push(last);
+        while let Some(ptr) = last.as_ref().next { last = ptr; }
+        self.tail = Some(last);
+    }
+}
+
+
+impl<'a, T> Drop for MergeGuard<'a, T> {
+    fn drop(&mut self) {
+        let (left, right, remaining) = (self.left.take(), self.right.take(), self.remaining.take());
+
+        unsafe {
+            self.append(left);
+            self.append(right);
+            self.append(remaining);
+
+            if let Some(mut ptr) = self.tail { ptr.as_mut().next = None; }
+            self.list.relink_chain(self.head);
+        }
+
+        self.list.length = self.length;
+    }
+}
+
+
+/// Cuts the chain starting at `node` after `count` Nodes, returning the first `Node` of the remainder.
+/// Only `next` pointers are followed, `previous` pointers are left for `LinkedList::relink_chain` to rebuild.
+unsafe fn cut_chain<T>(node: NonNull<Node<T>>, count: usize) -> Link<T> {
+    let mut last = node;
+    for _ in 1 .. count { last = last.as_ref().next?; }
+    return last.as_mut().next.take();
+}
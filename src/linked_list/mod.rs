@@ -1,18 +1,28 @@
 #[cfg(test)]
 mod tests;
 
-pub(crate) mod node;
-use node::Node;
+mod node;
+use node::{Node, Link};
 
-use std::boxed::Box;
-use std::vec::Vec;
+mod iter;
+pub use iter::{Iter, IterMut, IntoIter, ExtractIf};
 
-use core::mem::{swap as mem_swap, replace as mem_replace};
-use core::iter::{FusedIterator, FromIterator};
-use core::ptr::{NonNull, read as ptr_read};
+mod cursor;
+pub use cursor::{Cursor, CursorMut};
+
+mod merge;
+use merge::MergeGuard;
+
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use core::mem::{swap as mem_swap, replace as mem_replace, take as mem_take};
+use core::iter::FromIterator;
+use core::ptr::NonNull;
 use core::ops::{Index, IndexMut};
 use core::option::Option;
-use core::cmp::PartialEq;
+use core::cmp::{PartialEq, Ordering};
 use core::str::FromStr;
 use core::fmt;
 
@@ -30,9 +40,11 @@ pub mod macros {
     /// list.push_back(3);
     /// assert_eq!(list, list![1, 2, 3]);
     /// ```
+    #[macro_export]
     macro_rules! list {
         ( $($element:expr), * ) => {
             {
+                #[allow(unused_mut)]
                 let mut list = $crate::linked_list::LinkedList::new();
                 $( list.push_back($element); ) *
                 list
@@ -40,7 +52,7 @@ pub mod macros {
         };
     }
 
-    pub(crate) use list;
+    pub use list;
 }
 
 
@@ -55,61 +67,162 @@ pub mod macros {
 /// ```
 #[derive(Debug)]
 pub struct LinkedList<T> {
-    head: Option<NonNull<Node<T>>>,
-    tail: Option<NonNull<Node<T>>>,
+    head: Link<T>,
+    tail: Link<T>,
     length: usize,
 }
 
 
-/// Struct for converting a `LinkedList` into an `Iterator`.
-/// The `Iterator` is based off continuously popping the List's head and returning its data vale.
-/// 
-/// ## Implementation:
-/// ```rust
-/// impl<T> Iterator for IntoIter<T> {
-///    type Item = T;
-///
-///    #[inline(always)]
-///    fn next(&mut self) -> Option<T> {
-///        return self.list.pop_front();
-///    }
-/// }
-/// ```
-#[derive(Clone)]
-pub struct IntoIter<T> {
-    list: LinkedList<T>,
-}
-
-
 /* Private Methods */
 impl<T> LinkedList<T> {
-    /// Returns a reference to a `Node`'s data value if the `Node` is present at the given index,
-    /// and the passed `root` contains the `Node` at a given `next` reference.
-    #[inline]
-    fn get_node(&self, root: Option<NonNull<Node<T>>>, index: usize) -> Option<&Node<T>> {
-        match root {
-            Some(ptr) => match index {
-                0 => Some( unsafe{ &(*ptr.as_ptr()) } ),
-                _ => self.get_node( unsafe { (*ptr.as_ptr()).next }, index - 1 ),
-            },
-            
-            None => None,
+    /// Returns the `Node` at the given index if it exists, walking from whichever end of the `LinkedList` is closer.
+    fn node_at(&self, index: usize) -> Link<T> {
+        if index >= self.length { return None; }
+
+        unsafe {
+            if index < self.length / 2 {
+                let mut current = self.head?;
+                for _ in 0 .. index { current = current.as_ref().next?; }
+                return Some(current);
+            }
+
+            let mut current = self.tail?;
+            for _ in index + 1 .. self.length { current = current.as_ref().previous?; }
+            return Some(current);
         }
     }
 
-    /// Returns a mutable reference to a `Node`'s data value if the `Node` is present at the given index,
-    /// and the passed `root` contains the `Node` at a given `next` reference.
-    #[inline]
-    fn get_node_mut(&mut self, root: Option<NonNull<Node<T>>>, index: usize) -> Option<&mut Node<T>> {
-        match root {
-            Some(ptr) => match index {
-                0 => Some( unsafe{ &mut (*ptr.as_ptr()) } ),
-                _ => self.get_node_mut( unsafe { (*ptr.as_ptr()).next }, index - 1 ),
-            },
+    /// Allocates a new `Node` holding `data` and links it between `previous` and `next`,
+    /// which have to be adjacent within the `LinkedList`, `None` standing for either end of it.
+    unsafe fn link_between(&mut self, data: T, previous: Link<T>, next: Link<T>) -> NonNull<Node<T>> {
+        return self.attach_between(Box::new(Node::new(data)), previous, next);
+    }
 
-            None => None,
+    /// Links an owned `Node` between `previous` and `next`, which have to be adjacent within the `LinkedList`,
+    /// `None` standing for either end of it.
+    unsafe fn attach_between(&mut self, mut node: Box<Node<T>>, previous: Link<T>, next: Link<T>) -> NonNull<Node<T>> {
+        node.previous = previous;
+        node.next = next;
+
+        let node_ptr = NonNull::new_unchecked(Box::into_raw(node));
+
+        match previous {
+            Some(mut ptr) => ptr.as_mut().next = Some(node_ptr),
+            None => self.head = Some(node_ptr),
+        }
+
+        match next {
+            Some(mut ptr) => ptr.as_mut().previous = Some(node_ptr),
+            None => self.tail = Some(node_ptr),
+        }
+
+        self.length += 1;
+        return node_ptr;
+    }
+
+    /// Unlinks `node` from the `LinkedList`, relinking its neighbours and handing back ownership of it.
+    unsafe fn unlink_node(&mut self, node: NonNull<Node<T>>) -> Box<Node<T>> {
+        let node = Box::from_raw(node.as_ptr());
+
+        match node.previous {
+            Some(mut ptr) => ptr.as_mut().next = node.next,
+            None => self.head = node.next,
+        }
+
+        match node.next {
+            Some(mut ptr) => ptr.as_mut().previous = node.previous,
+            None => self.tail = node.previous,
+        }
+
+        self.length -= 1;
+        return node;
+    }
+
+    /// Moves every `Node` of `other` between `previous` and `next`, which have to be adjacent within the `LinkedList`.
+    unsafe fn splice_between(&mut self, previous: Link<T>, next: Link<T>, other: &mut Self) {
+        if let (Some(mut first), Some(mut last)) = (other.head.take(), other.tail.take()) {
+            first.as_mut().previous = previous;
+            last.as_mut().next = next;
+
+            match previous {
+                Some(mut ptr) => ptr.as_mut().next = Some(first),
+                None => self.head = Some(first),
+            }
+
+            match next {
+                Some(mut ptr) => ptr.as_mut().previous = Some(last),
+                None => self.tail = Some(last),
+            }
+
+            self.length += mem_replace(&mut other.length, 0);
         }
     }
+
+    /// Splits off every `Node` after `node`, which sits at index `at - 1`, returning them as a new `LinkedList`.
+    /// A `node` of `None` splits off the entire `LinkedList`.
+    unsafe fn split_after_node(&mut self, node: Link<T>, at: usize) -> Self {
+        let first = match node {
+            Some(ptr) => ptr.as_ref().next,
+            None => self.head,
+        };
+
+        let mut first = match first {
+            Some(ptr) => ptr,
+            None => return Self::new(),
+        };
+
+        let split = Self { head: Some(first), tail: self.tail, length: self.length - at };
+        first.as_mut().previous = None;
+
+        match node {
+            Some(mut ptr) => ptr.as_mut().next = None,
+            None => self.head = None,
+        }
+
+        self.tail = node;
+        self.length = at;
+        return split;
+    }
+
+    /// Splits off every `Node` before `node`, which sits at index `at`, returning them as a new `LinkedList`.
+    /// A `node` of `None` splits off the entire `LinkedList`.
+    unsafe fn split_before_node(&mut self, node: Link<T>, at: usize) -> Self {
+        let last = match node {
+            Some(ptr) => ptr.as_ref().previous,
+            None => self.tail,
+        };
+
+        let mut last = match last {
+            Some(ptr) => ptr,
+            None => return Self::new(),
+        };
+
+        let split = Self { head: self.head, tail: Some(last), length: at };
+        last.as_mut().next = None;
+
+        match node {
+            Some(mut ptr) => ptr.as_mut().previous = None,
+            None => self.tail = None,
+        }
+
+        self.head = node;
+        self.length -= at;
+        return split;
+    }
+
+    /// Rebuilds the `previous` pointers and the tail of the `LinkedList` from a chain linked only through `next`.
+    unsafe fn relink_chain(&mut self, head: Link<T>) {
+        let (mut previous, mut current) = (None, head);
+
+        while let Some(mut ptr) = current {
+            ptr.as_mut().previous = previous;
+            previous = Some(ptr);
+            current = ptr.as_ref().next;
+        }
+
+        self.head = head;
+        self.tail = previous;
+    }
 }
 
 
@@ -161,6 +274,7 @@ impl<T> LinkedList<T> {
     }
 
     /// Clears the `LinkedList`, making it completely empty and resetting its `length`.
+    /// Every `Node` is freed and its value dropped.
     /// 
     /// ## Example:
     /// ```rust
@@ -228,42 +342,175 @@ impl<T> LinkedList<T> {
         self.tail = node_ptr;
     }
 
-    /// Inserts a new `Node` into the `LinkedList` at the given index.
+    /// Inserts a new `Node` into the `LinkedList` at the given index, shifting every `Node` after it back by one.
+    /// An index equal to the `length` of the `LinkedList` appends the `Node`, any larger index panics.
     /// 
     /// ## Example:
     /// ```rust
     /// let mut list: LinkedList<u64> = list![1, 3];
-    /// list.insert(3, 1);
-    /// assert_eq!(list, list![1, 2, 3]);
-    /// assert_eq!(list.len(), 3);
-    /// assert_eq!(list[1], 3);
+    /// list.insert(2, 1);
+    /// list.insert(4, 3);
+    /// assert_eq!(list, list![1, 2, 3, 4]);
+    /// assert_eq!(list[1], 2);
     /// ```
     pub fn insert(&mut self, data: T, index: usize) {
+        assert!(index <= self.length, "Insertion index (is {}) should be <= len (is {}).", index, self.length);
+
+        let (previous, next) = match self.node_at(index) {
+            Some(ptr) => unsafe { (ptr.as_ref().previous, Some(ptr)) },
+            None => (self.tail, None),
+        };
+
+        unsafe { self.link_between(data, previous, next); }
+    }
+
+    /// Removes the `Node` at the given index and returns its `data` field, or `None` if the index is out of bounds.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3];
+    /// assert_eq!(list.remove(1), Some(2));
+    /// assert_eq!(list.remove(2), None);
+    /// assert_eq!(list, list![1, 3]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let node = unsafe { self.unlink_node(self.node_at(index)?) };
+        return Some(node.data);
+    }
+
+    /// Splits the `LinkedList` in two at the given index, returning every `Node` from `at` onwards as a new `LinkedList`.
+    /// Panics if `at` is greater than the `length` of the `LinkedList`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3, 4];
+    /// let back = list.split_off(1);
+    /// assert_eq!(list, list![1]);
+    /// assert_eq!(back, list![2, 3, 4]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.length, "Split index (is {}) should be <= len (is {}).", at, self.length);
+        if at == 0 { return mem_take(self); }
+
+        return unsafe { self.split_after_node(self.node_at(at - 1), at) };
+    }
+
+    /// Swaps the values at the given indices, panicking if either index is out of bounds.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3];
+    /// list.swap(0, 2);
+    /// assert_eq!(list, list![3, 2, 1]);
+    /// ```
+    pub fn swap(&mut self, i: usize, j: usize) {
+        let a = self.node_at(i).unwrap_or_else(|| panic!("Index (is {}) should be < len (is {}).", i, self.length));
+        let b = self.node_at(j).unwrap_or_else(|| panic!("Index (is {}) should be < len (is {}).", j, self.length));
+
+        if a != b {
+            unsafe { mem_swap(&mut (*a.as_ptr()).data, &mut (*b.as_ptr()).data); }
+        }
+    }
+
+    /// Rotates the `LinkedList` `n` places to the left, so the value at index `n` becomes the front.
+    /// Only the head and tail are relinked, panics if `n` is greater than the `length` of the `LinkedList`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3, 4];
+    /// list.rotate_left(1);
+    /// assert_eq!(list, list![2, 3, 4, 1]);
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.length, "Rotation (is {}) should be <= len (is {}).", n, self.length);
+        if n == 0 || n == self.length { return; }
+
+        unsafe {
+            let mut new_head = self.node_at(n).unwrap();
+            let mut new_tail = new_head.as_ref().previous.unwrap();
+            let (mut head, mut tail) = (self.head.unwrap(), self.tail.unwrap());
+
+            tail.as_mut().next = Some(head);
+            head.as_mut().previous = Some(tail);
+            new_tail.as_mut().next = None;
+            new_head.as_mut().previous = None;
+
+            self.head = Some(new_head);
+            self.tail = Some(new_tail);
+        }
+    }
+
+    /// Rotates the `LinkedList` `n` places to the right, so the value at index `len - n` becomes the front.
+    /// Only the head and tail are relinked, panics if `n` is greater than the `length` of the `LinkedList`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3, 4];
+    /// list.rotate_right(1);
+    /// assert_eq!(list, list![4, 1, 2, 3]);
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.length, "Rotation (is {}) should be <= len (is {}).", n, self.length);
+        self.rotate_left(self.length - n);
+    }
+
+    /// Reverses the order of the `LinkedList` in place by swapping the `previous` and `next` pointers of every `Node`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3];
+    /// list.reverse();
+    /// assert_eq!(list, list![3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
         let mut current = self.head;
-        let mut i = 0;
 
         while let Some(mut ptr) = current {
-            if index == i {
-                let mut new_node = Box::new(Node::new(data));
-
-                new_node.previous = unsafe { ptr.as_ref().previous };
-                new_node.next = Some(ptr);
-
-                unsafe {
-                    let mut node_ptr = NonNull::new_unchecked(Box::into_raw(new_node));
-                    ptr.as_mut().previous = Some(node_ptr);
-                    
-                    if let Some(mut ptr) = node_ptr.as_mut().previous {
-                        ptr.as_mut().next = Some(node_ptr);
-                    }
-                }
-
-                self.length += 1;
-                return;
+            unsafe {
+                let node = ptr.as_mut();
+                mem_swap(&mut node.previous, &mut node.next);
+                current = node.previous;
             }
+        }
 
-            i += 1;
-            current = unsafe { ptr.as_ref().next };
+        mem_swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Moves the `Node` at the given index to the front of the `LinkedList` without reallocating it,
+    /// panicking if the index is out of bounds.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3];
+    /// list.move_to_front(2);
+    /// assert_eq!(list, list![3, 1, 2]);
+    /// ```
+    pub fn move_to_front(&mut self, index: usize) {
+        let node = self.node_at(index).unwrap_or_else(|| panic!("Index (is {}) should be < len (is {}).", index, self.length));
+        if index == 0 { return; }
+
+        unsafe {
+            let node = self.unlink_node(node);
+            self.attach_between(node, None, self.head);
+        }
+    }
+
+    /// Moves the `Node` at the given index to the back of the `LinkedList` without reallocating it,
+    /// panicking if the index is out of bounds.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3];
+    /// list.move_to_back(0);
+    /// assert_eq!(list, list![2, 3, 1]);
+    /// ```
+    pub fn move_to_back(&mut self, index: usize) {
+        let node = self.node_at(index).unwrap_or_else(|| panic!("Index (is {}) should be < len (is {}).", index, self.length));
+        if index + 1 == self.length { return; }
+
+        unsafe {
+            let node = self.unlink_node(node);
+            self.attach_between(node, self.tail, None);
         }
     }
 
@@ -278,19 +525,17 @@ impl<T> LinkedList<T> {
     /// ```
     #[inline]
     pub fn pop_front(&mut self) -> Option<T> {
-        if let Some(mut ptr) = self.head {
-            let value: T;
-            
-            unsafe {
-                value = ptr_read(&mut (*ptr.as_mut()).data);
-                self.head = (*self.head.unwrap().as_ptr()).next;
-                if let Some(ptr) = self.head {
-                    (*ptr.as_ptr()).previous = None;
-                }
+        if let Some(ptr) = self.head {
+            let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+            self.head = node.next;
+
+            match self.head {
+                Some(ptr) => unsafe { (*ptr.as_ptr()).previous = None },
+                None => self.tail = None,
             }
             
             self.length -= 1;
-            return Some(value);
+            return Some(node.data);
         }
 
         return None;
@@ -307,19 +552,17 @@ impl<T> LinkedList<T> {
     /// ```
     #[inline]
     pub fn pop_back(&mut self) -> Option<T> {
-        if let Some(mut ptr) = self.tail {
-            let value: T;
-            
-            unsafe {
-                value = ptr_read(&mut (*ptr.as_mut()).data);
-                self.tail = (*self.tail.unwrap().as_ptr()).previous;
-                if let Some(ptr) = self.tail {
-                    (*ptr.as_ptr()).next = None;
-                }
+        if let Some(ptr) = self.tail {
+            let node = unsafe { Box::from_raw(ptr.as_ptr()) };
+            self.tail = node.previous;
+
+            match self.tail {
+                Some(ptr) => unsafe { (*ptr.as_ptr()).next = None },
+                None => self.head = None,
             }
             
             self.length -= 1;
-            return Some(value);
+            return Some(node.data);
         }
 
         return None;
@@ -333,18 +576,9 @@ impl<T> LinkedList<T> {
     /// list.remove_front();
     /// assert_eq!(list, list!["will", "be", "removed."]);
     /// ```
-    #[inline]
+    #[inline(always)]
     pub fn remove_front(&mut self) {
-        if let Some(ptr) = self.head {
-            unsafe {
-                self.head = (*ptr.as_ptr()).next;
-                if let Some(ptr) = self.head {
-                    (*ptr.as_ptr()).previous = None;
-                }
-            }
-
-            self.length -= 1;
-        }
+        self.pop_front();
     }
 
     /// Removes the last `Node` within the `LinkedList`.
@@ -355,20 +589,9 @@ impl<T> LinkedList<T> {
     /// list.remove_back();
     /// assert_eq!(list, list!["Please", "don't", "remove"]);
     /// ```
-    #[inline]
+    #[inline(always)]
     pub fn remove_back(&mut self) {
-        if let Some(ptr) = self.tail {
-            unsafe {
-                self.tail = (*ptr.as_ptr()).previous;
-                if let Some(ptr) = self.tail {
-                    (*ptr.as_ptr()).next = None;
-                }
-            }
-
-            self.length -= 1;
-        }
-
-        
+        self.pop_back();
     }
 
     /// Appends the contents of another list to the end of the list.
@@ -404,6 +627,7 @@ impl<T> LinkedList<T> {
     }
 
     /// Returns a reference to a `Node`'s data value if the `Node` is present at the given index.
+    /// Walks from whichever end of the `LinkedList` is closer to the index.
     /// 
     /// ## Example:
     /// ```rust
@@ -412,13 +636,7 @@ impl<T> LinkedList<T> {
     /// ```
     #[inline(always)]
     pub fn get(&self, index: usize) -> Option<&T> {
-        if index == 0 { return self.front(); }
-        else if index == self.length - 1 { return self.back(); }
-
-        match self.get_node(self.head, index) {
-            Some(node) => Some(&node.data),
-            None => None,
-        }
+        return self.node_at(index).map(|ptr| unsafe { &(*ptr.as_ptr()).data });
     }
 
     /// Returns a mutable reference to a `Node`'s data value if the `Node` is present at the given index.
@@ -430,13 +648,7 @@ impl<T> LinkedList<T> {
     /// ```
     #[inline(always)]
     pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index == 0 { return self.front_mut(); }
-        else if index == self.length - 1 { return self.back_mut(); }
-        
-        match self.get_node_mut(self.head, index) {
-            Some(node) => Some(&mut node.data),
-            None => None,
-        }
+        return self.node_at(index).map(|ptr| unsafe { &mut (*ptr.as_ptr()).data });
     }
 
     /// Returns a reference to the `Node` at the front of the list.
@@ -450,7 +662,7 @@ impl<T> LinkedList<T> {
 
     /// Returns a mutable reference to the `Node` at the front of the list.
     #[inline(always)]
-    pub fn front_mut(&self) -> Option<&mut T> {
+    pub fn front_mut(&mut self) -> Option<&mut T> {
         match self.head {
             Some(mut ptr) => unsafe { Some(&mut ptr.as_mut().data) },
             None => None,
@@ -468,13 +680,184 @@ impl<T> LinkedList<T> {
 
     /// Returns a mutable reference to the `Node` at the back of the list.
     #[inline(always)]
-    pub fn back_mut(&self) -> Option<&mut T> {
+    pub fn back_mut(&mut self) -> Option<&mut T> {
         match self.tail {
             Some(mut ptr) => unsafe { Some(&mut ptr.as_mut().data) },
             None => None,
         }
     }
 
+    /// Returns an iterator over references to the values of the `LinkedList`, from front to back.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let list: LinkedList<i32> = list![1, 2, 3];
+    /// let mut iter = list.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next_back(), Some(&3));
+    /// ```
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        return Iter::new(self.head, self.tail, self.length);
+    }
+
+    /// Returns an iterator over mutable references to the values of the `LinkedList`, from front to back.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3];
+    /// for e in list.iter_mut() { *e *= 10; }
+    /// assert_eq!(list, list![10, 20, 30]);
+    /// ```
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        return IterMut::new(self.head, self.tail, self.length);
+    }
+
+    /// Keeps only the values for which `keep` returns `true`, unlinking and dropping every other `Node` in a single pass.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3, 4];
+    /// list.retain(|e| e % 2 == 0);
+    /// assert_eq!(list, list![2, 4]);
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        self.retain_mut(|e| keep(e));
+    }
+
+    /// Keeps only the values for which `keep` returns `true`, passing each value mutably so it can be updated in place.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut keep: F) {
+        let mut current = self.head;
+
+        while let Some(ptr) = current {
+            unsafe {
+                current = ptr.as_ref().next;
+                if !keep(&mut (*ptr.as_ptr()).data) { drop(self.unlink_node(ptr)); }
+            }
+        }
+    }
+
+    /// Returns an `Iterator` that unlinks and yields every value for which `filter` returns `true`.
+    /// Values the `Iterator` has not reached when it is dropped are kept.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 2, 3, 4];
+    /// let evens: Vec<i32> = list.extract_if(|e| *e % 2 == 0).collect();
+    /// assert_eq!(evens, vec![2, 4]);
+    /// assert_eq!(list, list![1, 3]);
+    /// ```
+    #[inline(always)]
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, filter: F) -> ExtractIf<'_, T, F> {
+        return ExtractIf::new(self, filter);
+    }
+
+    /// Removes consecutive values for which `same_bucket` returns `true`, keeping the first of each run.
+    /// `same_bucket` receives the value being checked followed by the last value that was kept.
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let mut kept = match self.head {
+            Some(ptr) => ptr,
+            None => return,
+        };
+
+        unsafe {
+            while let Some(ptr) = kept.as_ref().next {
+                if same_bucket(&mut (*ptr.as_ptr()).data, &mut (*kept.as_ptr()).data) {
+                    drop(self.unlink_node(ptr));
+                } else {
+                    kept = ptr;
+                }
+            }
+        }
+    }
+
+    /// Removes consecutive values that map to the same key, keeping the first of each run.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![10, 11, 20, 21, 12];
+    /// list.dedup_by_key(|e| *e / 10);
+    /// assert_eq!(list, list![10, 20, 12]);
+    /// ```
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Sorts the `LinkedList` with a comparator function, preserving the order of equal values.
+    /// Uses a bottom-up merge sort that relinks the existing Nodes, running in `O(n log n)` without allocating.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![3, 1, 2];
+    /// list.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(list, list![3, 2, 1]);
+    /// ```
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let length = self.length;
+        let mut guard = MergeGuard::new(self);
+        let mut width = 1;
+
+        while width < length {
+            guard.restart();
+            while guard.next_runs(width) { guard.merge_runs(&mut compare); }
+            width *= 2;
+        }
+    }
+
+    /// Sorts the `LinkedList` by the key `key` extracts from each value, preserving the order of equal keys.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![-3, 1, -2];
+    /// list.sort_by_key(|e| e.abs());
+    /// assert_eq!(list, list![1, -2, -3]);
+    /// ```
+    #[inline]
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.sort_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Returns a `Cursor` pointing at the first value of the `LinkedList`,
+    /// or at the "ghost" position between the back and the front if it is empty.
+    #[inline(always)]
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        return Cursor::new(self, self.head, 0);
+    }
+
+    /// Returns a `Cursor` pointing at the last value of the `LinkedList`,
+    /// or at the "ghost" position between the back and the front if it is empty.
+    #[inline(always)]
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        return Cursor::new(self, self.tail, self.length.saturating_sub(1));
+    }
+
+    /// Returns a `CursorMut` pointing at the first value of the `LinkedList`,
+    /// or at the "ghost" position between the back and the front if it is empty.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 3];
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.insert_after(2);
+    /// assert_eq!(list, list![1, 2, 3]);
+    /// ```
+    #[inline(always)]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let head = self.head;
+        return CursorMut::new(self, head, 0);
+    }
+
+    /// Returns a `CursorMut` pointing at the last value of the `LinkedList`,
+    /// or at the "ghost" position between the back and the front if it is empty.
+    #[inline(always)]
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let (tail, index) = (self.tail, self.length.saturating_sub(1));
+        return CursorMut::new(self, tail, index);
+    }
+
     /// Returns the `LinkedList` converted into a `Vec`.
     /// 
     /// ## Example:
@@ -502,13 +885,87 @@ impl<T: PartialEq> LinkedList<T> {
     /// ```
     #[inline]
     pub fn search(&self, finding: T) -> Option<usize> {
-        for (i, e) in self.into_iter().enumerate() {
-            if e == finding {
-                return Some(i);
-            }
-        }
+        return self.iter().position(|e| *e == finding);
+    }
 
-        return None;
+    /// Returns `true` if any `Node` within the `LinkedList` contains the equivalent value of `finding`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let list: LinkedList<i32> = list![1, 2, 3];
+    /// assert!(list.contains(&2));
+    /// assert!(!list.contains(&4));
+    /// ```
+    #[inline]
+    pub fn contains(&self, finding: &T) -> bool {
+        return self.iter().any(|e| e == finding);
+    }
+
+    /// Removes consecutive equal values, keeping the first of each run.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 1, 2, 2, 2, 1];
+    /// list.dedup();
+    /// assert_eq!(list, list![1, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+}
+
+
+impl<T: Ord> LinkedList<T> {
+    /// Sorts the `LinkedList` in ascending order, preserving the order of equal values.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![4, 2, 3, 1];
+    /// list.sort();
+    /// assert_eq!(list, list![1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn sort(&mut self) {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+
+    /// Sorts the `LinkedList` in ascending order, the order of equal values is not guaranteed.
+    /// Relinking Nodes costs the same either way, so this currently shares the stable merge sort of `sort`.
+    #[inline]
+    pub fn sort_unstable(&mut self) {
+        self.sort();
+    }
+
+    /// Merges the values of `other`, which along with the `LinkedList` has to be sorted, into the `LinkedList`, leaving `other` empty.
+    /// Equal values from the `LinkedList` stay ahead of those from `other`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut list: LinkedList<i32> = list![1, 3, 5];
+    /// let mut other: LinkedList<i32> = list![2, 4];
+    /// list.merge(&mut other);
+    /// assert_eq!(list, list![1, 2, 3, 4, 5]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn merge(&mut self, other: &mut Self) {
+        let mut guard = MergeGuard::new(self);
+        guard.take_other(other);
+        guard.merge_runs(&mut |a: &T, b: &T| a.cmp(b));
+    }
+}
+
+
+impl<T: PartialOrd> LinkedList<T> {
+    /// Returns `true` if every value within the `LinkedList` is less than or equal to the value after it.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// assert!(list![1, 2, 2, 3].is_sorted());
+    /// assert!(!list![2, 1].is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool {
+        return self.iter().zip(self.iter().skip(1)).all(|(a, b)| a <= b);
     }
 }
 
@@ -522,39 +979,21 @@ impl<T> Default for LinkedList<T> {
 
 impl<T: fmt::Display> fmt::Display for LinkedList<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.length == 0 { return write!(f, "[]"); }
+        write!(f, "[")?;
 
-        let mut result = String::from("[");
-
-        for e in self.into_iter() {
-            result.push_str(format!("{}, ", e).as_str());
+        for (i, e) in self.iter().enumerate() {
+            if i != 0 { write!(f, ", ")?; }
+            write!(f, "{}", e)?;
         }
 
-        /*
-            SAFETY: Unwrapping will never result in a panic due to us checking the length
-            and returning an empty string representation of a list if it's equal to 0.
-        */
-        return write!(f, "{}", result.strip_suffix(", ").unwrap().to_string() + "]");
+        return write!(f, "]");
     }
 }
 
 
 impl<T: PartialEq> PartialEq for LinkedList<T> {
     fn eq(&self, other: &Self) -> bool {
-        if self.len() != other.len() { return false; }
-        if self.len() == 0 { return true; }
-
-        let (mut s, mut o) = (self.head, other.head);
-
-        while let (Some(s_ptr), Some(o_ptr)) = (s, o) {
-            unsafe {
-                if s_ptr.as_ref().data != o_ptr.as_ref().data { return false; }
-                s = (*s_ptr.as_ptr()).next;
-                o = (*o_ptr.as_ptr()).next;
-            }
-        }
-
-        return true;
+        return self.len() == other.len() && self.iter().eq(other.iter());
     }
 }
 
@@ -578,16 +1017,22 @@ impl<T> IndexMut<usize> for LinkedList<T> {
 }
 
 
-impl<T> Copy for LinkedList<T> {  }
+impl<T: Clone> Clone for LinkedList<T> {
+    /// Deep copies the `LinkedList`, allocating a new `Node` for a clone of every value.
+    fn clone(&self) -> Self {
+        return self.iter().cloned().collect();
+    }
+}
 
 
-impl<T> Clone for LinkedList<T> {
-    fn clone(&self) -> Self {
-        return *self;
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {  }
     }
 }
 
 
+#[allow(clippy::needless_range_loop)]
 impl<T: Copy> From<Vec<T>> for LinkedList<T> {
     fn from(vec: Vec<T>) -> Self {
         let mut list = Self::new();
@@ -597,6 +1042,7 @@ impl<T: Copy> From<Vec<T>> for LinkedList<T> {
 }
 
 
+#[allow(clippy::needless_range_loop)]
 impl<T: Copy> From<&[T]> for LinkedList<T> {
     fn from(slice: &[T]) -> Self {
         let mut list = Self::new();
@@ -606,6 +1052,7 @@ impl<T: Copy> From<&[T]> for LinkedList<T> {
 }
 
 
+#[allow(clippy::needless_range_loop)]
 impl<T: Copy, const N: usize> From<[T; N]> for LinkedList<T> {
     fn from(arr: [T; N]) -> Self {
         let mut list = Self::new();
@@ -636,40 +1083,35 @@ impl<T: FromStr> FromStr for LinkedList<T> {
 }
 
 
-impl<T> Iterator for IntoIter<T> {
+impl<T> IntoIterator for LinkedList<T> {
     type Item = T;
+    type IntoIter = IntoIter<T>;
 
     #[inline(always)]
-    fn next(&mut self) -> Option<T> {
-        return self.list.pop_front();
-    }
-
-    #[inline(always)]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        return (self.list.length, Some(self.list.length));
+    fn into_iter(self) -> Self::IntoIter {
+        return IntoIter::new(self);
     }
 }
 
 
-impl<T> DoubleEndedIterator for IntoIter<T> {
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
     #[inline(always)]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        return self.list.pop_back();
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
     }
 }
 
 
-impl<T> ExactSizeIterator for IntoIter<T> {  }
-impl<T> FusedIterator for IntoIter<T> {  }
-
-
-impl<T> IntoIterator for LinkedList<T> {
-    type Item = T;
-    type IntoIter = IntoIter<T>;
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
 
     #[inline(always)]
     fn into_iter(self) -> Self::IntoIter {
-        return Self::IntoIter { list: self };
+        return self.iter_mut();
     }
 }
 
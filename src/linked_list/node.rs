@@ -4,6 +4,10 @@ use core::ptr::NonNull;
 use core::fmt;
 
 
+/// Nullable pointer to a `Node`, used for the head and tail of a List and the neighbours of every `Node`.
+pub type Link<T> = Option<NonNull<Node<T>>>;
+
+
 /// `Node` to be used in creating a `LinkedList`.
 /// ## Fields:
 /// ```rust
@@ -13,8 +17,8 @@ use core::fmt;
 /// ```
 #[derive(Debug)]
 pub struct Node<T> {
-    pub previous: Option<NonNull<Node<T>>>,
-    pub next: Option<NonNull<Node<T>>>,
+    pub previous: Link<T>,
+    pub next: Link<T>,
     pub data: T,
 }
 
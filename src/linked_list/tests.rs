@@ -1,4 +1,6 @@
 use super::{LinkedList, macros::list};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
 
 
 #[test]
@@ -70,6 +72,7 @@ fn clear() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn is_empty() {
     let mut list: LinkedList<&str> = list!["I", "am", "not", "empty."];
     assert_eq!(list.is_empty(), false);
@@ -214,4 +217,517 @@ fn iterator() {
 #[test]
 fn from_iterator() {
     assert_eq!(vec![1, 2, 3].into_iter().collect::<LinkedList<i32>>(), list![1, 2, 3]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn clone_is_deep() {
+    let original: LinkedList<String> = list![String::from("a"), String::from("b")];
+    let mut cloned = original.clone();
+
+    assert_eq!(cloned.pop_front(), Some(String::from("a")));
+    cloned.push_back(String::from("c"));
+    cloned[0].push('!');
+
+    assert_eq!(original, list![String::from("a"), String::from("b")]);
+    assert_eq!(cloned, list![String::from("b!"), String::from("c")]);
+}
+
+#[test]
+fn pop_until_empty() {
+    let mut list: LinkedList<i32> = list![1, 2];
+    assert_eq!(list.pop_back(), Some(2));
+    assert_eq!(list.pop_back(), Some(1));
+    assert_eq!(list.pop_front(), None);
+    assert!(list.front().is_none() && list.back().is_none());
+
+    list.push_back(3);
+    assert_eq!(list.pop_front(), Some(3));
+    list.push_front(4);
+    assert_eq!(list, list![4]);
+}
+
+#[test]
+fn drop_frees_every_value_once() {
+    let rc = Rc::new(());
+    let mut list: LinkedList<Rc<()>> = (0 .. 8).map(|_| rc.clone()).collect();
+    assert_eq!(Rc::strong_count(&rc), 9);
+
+    list.remove_front();
+    list.remove_back();
+    assert_eq!(Rc::strong_count(&rc), 7);
+
+    let popped = list.pop_front();
+    assert_eq!(Rc::strong_count(&rc), 7);
+    drop(popped);
+    assert_eq!(Rc::strong_count(&rc), 6);
+
+    let cloned = list.clone();
+    assert_eq!(Rc::strong_count(&rc), 11);
+
+    drop(list);
+    assert_eq!(Rc::strong_count(&rc), 6);
+
+    let mut iter = cloned.into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let mut list: LinkedList<Rc<()>> = (0 .. 3).map(|_| rc.clone()).collect();
+    list.clear();
+    assert_eq!(Rc::strong_count(&rc), 1);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn iter() {
+    let list: LinkedList<i32> = list![1, 2, 3, 4];
+    let mut iter = list.iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.len(), 2);
+
+    let rest = iter.clone();
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(rest.collect::<Vec<_>>(), vec![&2, &3]);
+
+    let reversed: Vec<i32> = (&list).into_iter().rev().copied().collect();
+    assert_eq!(reversed, vec![4, 3, 2, 1]);
+    assert_eq!(LinkedList::<i32>::new().iter().next(), None);
+}
+
+#[test]
+fn iter_mut() {
+    let mut list: LinkedList<i32> = list![1, 2, 3];
+    for e in &mut list { *e *= 10; }
+    assert_eq!(list, list![10, 20, 30]);
+
+    let mut iter = list.iter_mut();
+    *iter.next_back().unwrap() = 0;
+    *iter.next().unwrap() = 0;
+    assert_eq!(iter.len(), 1);
+    assert_eq!(list, list![0, 20, 0]);
+}
+
+#[test]
+fn cursor_moves() {
+    let list: LinkedList<i32> = list![1, 2, 3];
+    let mut cursor = list.cursor_front();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&1)));
+    assert_eq!((cursor.peek_prev(), cursor.peek_next()), (None, Some(&2)));
+
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&3)));
+
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+    assert_eq!((cursor.peek_prev(), cursor.peek_next()), (Some(&3), Some(&1)));
+
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&1));
+
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&3)));
+
+    let cursor = list.cursor_back();
+    assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&3)));
+
+    let empty: LinkedList<i32> = LinkedList::new();
+    let mut cursor = empty.cursor_back();
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+}
+
+#[test]
+fn cursor_insert() {
+    let mut list: LinkedList<i32> = list![2, 4];
+    let mut cursor = list.cursor_front_mut();
+    cursor.insert_before(1);
+    cursor.insert_after(3);
+    assert_eq!((cursor.index(), cursor.current()), (Some(1), Some(&mut 2)));
+
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.index(), None);
+    cursor.insert_after(0);
+    cursor.insert_before(5);
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (Some(5), Some(&mut 5)));
+    assert_eq!(list, list![0, 1, 2, 3, 4, 5]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1, 0]);
+
+    let mut empty: LinkedList<i32> = LinkedList::new();
+    empty.cursor_front_mut().insert_after(1);
+    empty.cursor_back_mut().insert_after(2);
+    assert_eq!(empty, list![1, 2]);
+}
+
+#[test]
+fn cursor_remove_current() {
+    let mut list: LinkedList<i32> = list![1, 2, 3];
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(2));
+    assert_eq!((cursor.index(), cursor.current()), (Some(1), Some(&mut 3)));
+
+    assert_eq!(cursor.remove_current(), Some(3));
+    assert_eq!(cursor.index(), None);
+    assert_eq!(cursor.remove_current(), None);
+
+    cursor.move_prev();
+    assert_eq!(cursor.remove_current(), Some(1));
+    assert!(list.is_empty());
+    assert!(list.front().is_none() && list.back().is_none());
+}
+
+#[test]
+fn cursor_split() {
+    let mut list: LinkedList<i32> = list![1, 2, 3, 4, 5];
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.move_next();
+
+    let after = cursor.split_after();
+    assert_eq!(cursor.index(), Some(2));
+    let before = cursor.split_before();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 3)));
+
+    assert_eq!(list, list![3]);
+    assert_eq!(before, list![1, 2]);
+    assert_eq!(after, list![4, 5]);
+    assert_eq!(after.len() + before.len() + list.len(), 5);
+
+    let mut cursor = list.cursor_back_mut();
+    assert!(cursor.split_after().is_empty());
+    cursor.move_next();
+    assert_eq!(cursor.split_before(), list![3]);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn cursor_splice() {
+    let mut list: LinkedList<i32> = list![1, 5];
+    let mut cursor = list.cursor_front_mut();
+    cursor.splice_after(list![3, 4]);
+    cursor.move_next();
+    cursor.splice_before(list![2]);
+    assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&mut 3)));
+
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.splice_after(list![0]);
+    cursor.splice_before(list![6]);
+    cursor.splice_after(LinkedList::new());
+    assert_eq!(cursor.as_cursor().index(), None);
+
+    assert_eq!(list, list![0, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(list.len(), 7);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![6, 5, 4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn insert_at_ends() {
+    let mut list: LinkedList<i32> = LinkedList::new();
+    list.insert(2, 0);
+    list.insert(0, 0);
+    list.insert(3, 2);
+    list.insert(1, 1);
+    assert_eq!(list, list![0, 1, 2, 3]);
+    assert_eq!(list.front(), Some(&0));
+    assert_eq!(list.back(), Some(&3));
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+}
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds() {
+    let mut list: LinkedList<i32> = list![1, 2];
+    list.insert(3, 3);
+}
+
+#[test]
+fn remove_index() {
+    let mut list: LinkedList<i32> = list![1, 2, 3, 4, 5];
+    assert_eq!(list.remove(5), None);
+    assert_eq!(list.remove(3), Some(4));
+    assert_eq!(list.remove(0), Some(1));
+    assert_eq!(list.remove(2), Some(5));
+    assert_eq!(list, list![2, 3]);
+    assert_eq!(list.back(), Some(&3));
+
+    assert_eq!(list.remove(0), Some(2));
+    assert_eq!(list.remove(0), Some(3));
+    assert!(list.is_empty());
+    assert_eq!(list.get(0), None);
+}
+
+#[test]
+fn get_from_either_end() {
+    let list: LinkedList<i32> = (0 .. 9).collect();
+    for i in 0 .. 9 { assert_eq!(list.get(i), Some(&(i as i32))); }
+    assert_eq!(list.get(9), None);
+    assert_eq!(LinkedList::<i32>::new().get(3), None);
+}
+
+#[test]
+fn split_off() {
+    let mut list: LinkedList<i32> = list![1, 2, 3, 4];
+    let back = list.split_off(3);
+    assert_eq!((list.len(), back.len()), (3, 1));
+    assert_eq!(back, list![4]);
+
+    assert!(list.split_off(3).is_empty());
+
+    let all = list.split_off(0);
+    assert!(list.is_empty());
+    assert_eq!(all, list![1, 2, 3]);
+    assert_eq!(all.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+}
+
+#[test]
+#[should_panic]
+fn split_off_out_of_bounds() {
+    let mut list: LinkedList<i32> = list![1, 2];
+    list.split_off(3);
+}
+
+#[test]
+fn swap() {
+    let mut list: LinkedList<i32> = list![1, 2, 3, 4];
+    list.swap(0, 3);
+    list.swap(1, 1);
+    list.swap(2, 1);
+    assert_eq!(list, list![4, 3, 2, 1]);
+}
+
+#[test]
+fn rotate() {
+    let mut list: LinkedList<i32> = list![1, 2, 3, 4, 5];
+    list.rotate_left(2);
+    assert_eq!(list, list![3, 4, 5, 1, 2]);
+    list.rotate_right(2);
+    assert_eq!(list, list![1, 2, 3, 4, 5]);
+
+    list.rotate_left(0);
+    list.rotate_right(5);
+    assert_eq!(list, list![1, 2, 3, 4, 5]);
+
+    list.rotate_right(1);
+    assert_eq!(list, list![5, 1, 2, 3, 4]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1, 5]);
+}
+
+#[test]
+fn retain() {
+    let mut list: LinkedList<i32> = list![1, 2, 3, 4, 5, 6];
+    list.retain(|e| e % 2 == 0);
+    assert_eq!(list, list![2, 4, 6]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![6, 4, 2]);
+
+    list.retain_mut(|e| { *e *= 10; *e != 40 });
+    assert_eq!(list, list![20, 60]);
+
+    list.retain(|_| false);
+    assert!(list.is_empty());
+    assert_eq!(list.iter().next_back(), None);
+}
+
+#[test]
+fn extract_if() {
+    let mut list: LinkedList<i32> = list![1, 2, 3, 4, 5];
+    let evens: Vec<i32> = list.extract_if(|e| *e % 2 == 0).collect();
+    assert_eq!(evens, vec![2, 4]);
+    assert_eq!(list, list![1, 3, 5]);
+
+    /* Values the iterator never reached are kept. */
+    assert_eq!(list.extract_if(|_| true).next(), Some(1));
+    assert_eq!(list, list![3, 5]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![5, 3]);
+}
+
+#[test]
+fn dedup() {
+    let mut list: LinkedList<i32> = list![1, 1, 2, 2, 2, 3, 1, 1];
+    list.dedup();
+    assert_eq!(list, list![1, 2, 3, 1]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![1, 3, 2, 1]);
+
+    let mut list: LinkedList<i32> = list![10, 11, 20, 21, 12];
+    list.dedup_by_key(|e| *e / 10);
+    assert_eq!(list, list![10, 20, 12]);
+
+    let mut list: LinkedList<i32> = LinkedList::new();
+    list.dedup();
+    assert!(list.is_empty());
+}
+
+#[test]
+fn contains() {
+    let list: LinkedList<i32> = list![1, 2, 3];
+    assert!(list.contains(&3));
+    assert!(!list.contains(&4));
+    assert!(!LinkedList::<i32>::new().contains(&1));
+}
+
+#[test]
+fn sort() {
+    let mut list: LinkedList<i32> = list![5, 3, 9, 1, 4, 1, 8, 2, 7];
+    list.sort();
+    assert_eq!(list, list![1, 1, 2, 3, 4, 5, 7, 8, 9]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![9, 8, 7, 5, 4, 3, 2, 1, 1]);
+    assert!(list.is_sorted());
+
+    list.sort_by(|a, b| b.cmp(a));
+    assert_eq!(list, list![9, 8, 7, 5, 4, 3, 2, 1, 1]);
+    assert!(!list.is_sorted());
+
+    let mut list: LinkedList<i32> = list![1];
+    list.sort_unstable();
+    assert_eq!(list, list![1]);
+
+    let mut list: LinkedList<i32> = LinkedList::new();
+    list.sort();
+    assert!(list.is_empty() && list.is_sorted());
+}
+
+#[test]
+fn sort_is_stable() {
+    let mut list: LinkedList<(i32, char)> = list![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')];
+    list.sort_by_key(|e| e.0);
+    assert_eq!(list, list![(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+}
+
+#[test]
+fn sort_panic_keeps_list_consistent() {
+    let rc = Rc::new(());
+
+    /* Panic at every compare of the sort, covering the first, middle and last passes. */
+    for panic_at in 1 .. 40 {
+        let mut list: LinkedList<(i32, Rc<()>)> = (0 .. 11).rev().map(|e| (e, rc.clone())).collect();
+        let mut compares = 0;
+
+        let _ = catch_unwind(AssertUnwindSafe(|| list.sort_by(|a, b| {
+            compares += 1;
+            if compares == panic_at { panic!("Comparator panicked."); }
+            return a.0.cmp(&b.0);
+        })));
+
+        assert_eq!(list.len(), 11);
+        assert_eq!(list.iter().count(), 11);
+        assert_eq!(list.iter().rev().count(), 11);
+        assert_eq!(Rc::strong_count(&rc), 12);
+
+        let mut forward: Vec<i32> = list.iter().map(|e| e.0).collect();
+        let mut backward: Vec<i32> = list.iter().rev().map(|e| e.0).collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        forward.sort_unstable();
+        assert_eq!(forward, (0 .. 11).collect::<Vec<_>>());
+
+        list.sort_by_key(|e| e.0);
+        assert!(list.iter().map(|e| e.0).eq(0 .. 11));
+
+        drop(list);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
+
+#[test]
+fn merge_panic_keeps_list_consistent() {
+    #[derive(PartialEq, Eq)]
+    struct Loud(i32, Rc<()>);
+
+    impl PartialOrd for Loud {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            return Some(self.cmp(other));
+        }
+    }
+
+    impl Ord for Loud {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            if self.0 == 4 || other.0 == 4 { panic!("Comparator panicked."); }
+            return self.0.cmp(&other.0);
+        }
+    }
+
+    let rc = Rc::new(());
+    let mut list: LinkedList<Loud> = [1, 3, 5, 7].iter().map(|&e| Loud(e, rc.clone())).collect();
+    let mut other: LinkedList<Loud> = [2, 4, 6].iter().map(|&e| Loud(e, rc.clone())).collect();
+
+    assert!(catch_unwind(AssertUnwindSafe(|| list.merge(&mut other))).is_err());
+
+    assert!(other.is_empty());
+    assert_eq!(other.iter().next_back().map(|e| e.0), None);
+    assert_eq!(list.len(), 7);
+    assert_eq!(list.iter().count(), 7);
+    assert_eq!(list.iter().rev().count(), 7);
+    assert_eq!(Rc::strong_count(&rc), 8);
+
+    drop((list, other));
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn merge() {
+    let mut list: LinkedList<i32> = list![1, 3, 5, 7];
+    let mut other: LinkedList<i32> = list![0, 2, 3, 8];
+    list.merge(&mut other);
+    assert_eq!(list, list![0, 1, 2, 3, 3, 5, 7, 8]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![8, 7, 5, 3, 3, 2, 1, 0]);
+    assert!(other.is_empty());
+
+    let mut empty: LinkedList<i32> = LinkedList::new();
+    empty.merge(&mut list);
+    assert_eq!(empty.len(), 8);
+    assert!(empty.is_sorted());
+}
+
+#[test]
+fn reverse() {
+    let mut list: LinkedList<i32> = list![1, 2, 3, 4];
+    list.reverse();
+    assert_eq!(list, list![4, 3, 2, 1]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    assert_eq!((list.front(), list.back()), (Some(&4), Some(&1)));
+
+    let mut list: LinkedList<i32> = list![1];
+    list.reverse();
+    assert_eq!(list, list![1]);
+
+    let mut list: LinkedList<i32> = LinkedList::new();
+    list.reverse();
+    assert!(list.is_empty());
+}
+
+#[test]
+fn move_to_front_and_back() {
+    let mut list: LinkedList<i32> = list![1, 2, 3, 4];
+    let third: *const i32 = &list[2];
+
+    list.move_to_front(2);
+    assert_eq!(list, list![3, 1, 2, 4]);
+    assert!(std::ptr::eq(&list[0], third));
+
+    list.move_to_front(0);
+    list.move_to_back(3);
+    assert_eq!(list, list![3, 1, 2, 4]);
+
+    list.move_to_back(0);
+    assert_eq!(list, list![1, 2, 4, 3]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![3, 4, 2, 1]);
+}
+
+#[test]
+#[should_panic]
+fn move_to_front_out_of_bounds() {
+    let mut list: LinkedList<i32> = list![1, 2];
+    list.move_to_front(2);
+}
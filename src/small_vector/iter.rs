@@ -0,0 +1,215 @@
+use super::{SmallVector, Storage};
+use crate::vector::IntoIter as VectorIntoIter;
+
+use core::ptr::{drop_in_place, slice_from_raw_parts_mut, read as ptr_read};
+use core::mem::{take as mem_take, replace as mem_replace, MaybeUninit};
+use core::iter::FusedIterator;
+use core::option::Option;
+use core::slice;
+
+
+/// Struct for iterating over references to the values of a `SmallVector`, created by `SmallVector::iter`.
+///
+/// ## Fields:
+/// ```rust
+/// inner: slice::Iter<'a, T> // Iterator over the initialized values of the SmallVector.
+/// ```
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    inner: slice::Iter<'a, T>,
+}
+
+
+/// Struct for iterating over mutable references to the values of a `SmallVector`, created by `SmallVector::iter_mut`.
+///
+/// ## Fields:
+/// ```rust
+/// inner: slice::IterMut<'a, T> // Iterator over the initialized values of the SmallVector.
+/// ```
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    inner: slice::IterMut<'a, T>,
+}
+
+
+/// Struct for iterating over the values of a `SmallVector` by value, created by `SmallVector::into_iter`.
+///
+/// ## Fields:
+/// ```rust
+/// inner: Inner<T, N> // Inline values or the consuming iterator of the spilled Vector.
+/// ```
+pub struct IntoIter<T, const N: usize> {
+    inner: Inner<T, N>,
+}
+
+
+/// Where the values of an `IntoIter` are read from.
+enum Inner<T, const N: usize> {
+    /// Values moved out of the inline buffer, only the ones between `front` and `back` are initialized.
+    Inline { buffer: [MaybeUninit<T>; N], front: usize, back: usize },
+
+    /// Values of a `SmallVector` that had spilled to the heap.
+    Heap(VectorIntoIter<T>),
+}
+
+
+impl<'a, T> Iter<'a, T> {
+    pub(super) fn new(values: &'a [T]) -> Self {
+        return Self { inner: values.iter() };
+    }
+
+    /// Returns the values that have not been yielded yet as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &'a [T] {
+        return self.inner.as_slice();
+    }
+}
+
+
+impl<'a, T> IterMut<'a, T> {
+    pub(super) fn new(values: &'a mut [T]) -> Self {
+        return Self { inner: values.iter_mut() };
+    }
+
+    /// Consumes the `IterMut`, returning the values that have not been yielded yet as a mutable slice.
+    #[inline(always)]
+    pub fn into_slice(self) -> &'a mut [T] {
+        return self.inner.into_slice();
+    }
+}
+
+
+impl<T, const N: usize> IntoIter<T, N> {
+    pub(super) fn new(mut small: SmallVector<T, N>) -> Self {
+        let inner = match &mut small.storage {
+            /* The length is zeroed before the buffer is copied out, so `small` no longer drops the values. */
+            Storage::Inline { buffer, length } => {
+                let back = mem_replace(length, 0);
+                Inner::Inline { buffer: unsafe { ptr_read(buffer) }, front: 0, back }
+            },
+
+            Storage::Heap(vector) => Inner::Heap(mem_take(vector).into_iter()),
+        };
+
+        return Self { inner };
+    }
+
+    /// Returns the values that have not been yielded yet as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        match &self.inner {
+            Inner::Inline { buffer, front, back } => unsafe {
+                return slice::from_raw_parts((buffer.as_ptr() as *const T).add(*front), back - front);
+            },
+
+            Inner::Heap(iter) => return iter.as_slice(),
+        }
+    }
+}
+
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.inner.next();
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.inner.next_back();
+    }
+}
+
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {  }
+impl<'a, T> FusedIterator for Iter<'a, T> {  }
+
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.inner.next();
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.inner.next_back();
+    }
+}
+
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {  }
+impl<'a, T> FusedIterator for IterMut<'a, T> {  }
+
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            Inner::Inline { buffer, front, back } => {
+                if front == back { return None; }
+
+                *front += 1;
+                unsafe { return Some(ptr_read(buffer[*front - 1].as_ptr())); }
+            },
+
+            Inner::Heap(iter) => return iter.next(),
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.as_slice().len();
+        return (remaining, Some(remaining));
+    }
+}
+
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            Inner::Inline { buffer, front, back } => {
+                if front == back { return None; }
+
+                *back -= 1;
+                unsafe { return Some(ptr_read(buffer[*back].as_ptr())); }
+            },
+
+            Inner::Heap(iter) => return iter.next_back(),
+        }
+    }
+}
+
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {  }
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {  }
+
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        /* Nothing else owns the copied out inline buffer, so the values between `front` and `back` are dropped here. */
+        if let Inner::Inline { buffer, front, back } = &mut self.inner {
+            unsafe { drop_in_place(slice_from_raw_parts_mut((buffer.as_mut_ptr() as *mut T).add(*front), *back - *front)); }
+        }
+    }
+}
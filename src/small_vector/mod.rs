@@ -0,0 +1,464 @@
+#[cfg(test)]
+mod tests;
+
+mod iter;
+
+pub use iter::{Iter, IterMut, IntoIter};
+
+use crate::vector::Vector;
+
+use core::ptr::{drop_in_place, slice_from_raw_parts_mut, read as ptr_read, copy as ptr_copy};
+use core::ops::{Index, IndexMut, Deref, DerefMut};
+use core::slice::{self, SliceIndex};
+use core::hash::{Hash, Hasher};
+use core::mem::MaybeUninit;
+use core::iter::FromIterator;
+use core::option::Option;
+use core::fmt;
+
+
+/// Shorthand Syntax for creating a new `SmallVector`, the inline capacity is inferred from the type.
+/// ## Example:
+/// ```rust
+/// let small: SmallVector<i32, 4> = small_vector![1, 2, 3];
+/// assert_eq!(small, [1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! small_vector {
+    ($($e:expr), *) => {
+        {
+            #[allow(unused_mut)]
+            let mut vec = $crate::small_vector::SmallVector::new();
+            $(
+                vec.push($e);
+            )*
+            vec
+        }
+    };
+}
+
+
+/// `Vector` storing up to `N` values inline, only moving them to the heap once more are pushed.
+/// ## Fields:
+/// ```rust
+/// storage: Storage<T, N> // Inline buffer, or the heap-backed Vector once spilled.
+/// ```
+pub struct SmallVector<T, const N: usize> {
+    storage: Storage<T, N>,
+}
+
+
+/// Where the values of a `SmallVector` live.
+enum Storage<T, const N: usize> {
+    /// Values are stored within the `SmallVector`, only the first `length` are initialized.
+    Inline { buffer: [MaybeUninit<T>; N], length: usize },
+
+    /// Values outgrew the inline buffer and were moved into a `Vector`.
+    Heap(Vector<T>),
+}
+
+
+impl<T, const N: usize> SmallVector<T, N> {
+    /// Constructs a new empty `SmallVector`, nothing is allocated until more than `N` values are pushed.
+    pub fn new() -> Self {
+        /* An array of `MaybeUninit` is valid without being initialized. */
+        let buffer = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
+        return Self { storage: Storage::Inline { buffer, length: 0 } };
+    }
+
+    pub fn len(&self) -> usize {
+        return match &self.storage {
+            Storage::Inline { length, .. } => *length,
+            Storage::Heap(vector) => vector.len(),
+        };
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    /// Returns the amount of values the `SmallVector` can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        return match &self.storage {
+            Storage::Inline { .. } => N,
+            Storage::Heap(vector) => vector.capacity(),
+        };
+    }
+
+    /// Returns `true` if the values of the `SmallVector` have been moved to the heap.
+    pub fn spilled(&self) -> bool {
+        return matches!(self.storage, Storage::Heap(_));
+    }
+
+    /// Pushes a new value into the `SmallVector`, moving every value to the heap if the inline buffer is full.
+    /// ## Example:
+    /// ```rust
+    /// let mut small: SmallVector<i32, 2> = SmallVector::new();
+    /// small.push(1);
+    /// small.push(2);
+    /// assert!(!small.spilled());
+    /// small.push(3);
+    /// assert!(small.spilled());
+    /// assert_eq!(small, [1, 2, 3]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        match &mut self.storage {
+            Storage::Inline { buffer, length } if *length < N => {
+                buffer[*length] = MaybeUninit::new(value);
+                *length += 1;
+            },
+
+            Storage::Inline { .. } => {
+                self.spill();
+                self.push(value);
+            },
+
+            Storage::Heap(vector) => vector.push(value),
+        }
+    }
+
+    /// Removes the last value from the `SmallVector` and returns it.
+    pub fn pop(&mut self) -> Option<T> {
+        match &mut self.storage {
+            Storage::Inline { buffer, length } => {
+                if *length == 0 { return None; }
+
+                *length -= 1;
+                unsafe { return Some(ptr_read(buffer[*length].as_ptr())); }
+            },
+
+            Storage::Heap(vector) => return vector.pop(),
+        }
+    }
+
+    /// Inserts `value` at `idx`, shifting every value after it to the right.
+    /// Moves every value to the heap if the inline buffer is full, panics if `idx > len`.
+    /// ## Example:
+    /// ```rust
+    /// let mut small: SmallVector<i32, 4> = small_vector![1, 3];
+    /// small.insert(1, 2);
+    /// assert_eq!(small, [1, 2, 3]);
+    /// ```
+    pub fn insert(&mut self, idx: usize, value: T) {
+        let length = self.len();
+        assert!(idx <= length, "Insertion index (is {}) should be <= len (is {}).", idx, length);
+
+        match &mut self.storage {
+            Storage::Inline { buffer, length } if *length < N => unsafe {
+                let pointer = (buffer.as_mut_ptr() as *mut T).add(idx);
+                ptr_copy(pointer, pointer.add(1), *length - idx);
+                pointer.write(value);
+                *length += 1;
+            },
+
+            Storage::Inline { .. } => {
+                self.spill();
+                self.insert(idx, value);
+            },
+
+            Storage::Heap(vector) => vector.insert(idx, value),
+        }
+    }
+
+    /// Removes the value at `idx` and returns it, shifting every value after it to the left.
+    /// Panics if `idx` is out of bounds.
+    /// ## Example:
+    /// ```rust
+    /// let mut small: SmallVector<i32, 4> = small_vector![1, 2, 3];
+    /// assert_eq!(small.remove(1), 2);
+    /// assert_eq!(small, [1, 3]);
+    /// ```
+    pub fn remove(&mut self, idx: usize) -> T {
+        let length = self.len();
+        assert!(idx < length, "Removal index (is {}) should be < len (is {}).", idx, length);
+
+        match &mut self.storage {
+            Storage::Inline { buffer, length } => unsafe {
+                let pointer = (buffer.as_mut_ptr() as *mut T).add(idx);
+                let value = ptr_read(pointer);
+                ptr_copy(pointer.add(1), pointer, *length - idx - 1);
+                *length -= 1;
+                return value;
+            },
+
+            Storage::Heap(vector) => return vector.remove(idx),
+        }
+    }
+
+    /// Shortens the `SmallVector` to `length` values, dropping the rest.
+    /// Does nothing if `length` is greater than or equal to the current length.
+    pub fn truncate(&mut self, length: usize) {
+        match &mut self.storage {
+            Storage::Inline { buffer, length: current } => {
+                if length >= *current { return; }
+
+                let remaining = *current - length;
+                *current = length;
+
+                unsafe { drop_in_place(slice_from_raw_parts_mut((buffer.as_mut_ptr() as *mut T).add(length), remaining)); }
+            },
+
+            Storage::Heap(vector) => vector.truncate(length),
+        }
+    }
+
+    /// Drops every value within the `SmallVector`, keeping its current storage.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Keeps only the values for which `keep` returns `true`, preserving their order.
+    /// ## Example:
+    /// ```rust
+    /// let mut small: SmallVector<i32, 4> = small_vector![1, 2, 3, 4];
+    /// small.retain(|x| x % 2 == 0);
+    /// assert_eq!(small, [2, 4]);
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        match &mut self.storage {
+            Storage::Inline { buffer, length: current } => {
+                let length = *current;
+                let mut deleted = 0;
+
+                /* The length is zeroed while shifting, so a panic within `keep` leaks values instead of dropping them twice. */
+                *current = 0;
+
+                for i in 0 .. length {
+                    unsafe {
+                        let pointer = (buffer.as_mut_ptr() as *mut T).add(i);
+
+                        if !keep(&*pointer) {
+                            drop_in_place(pointer);
+                            deleted += 1;
+                        } else if deleted > 0 {
+                            ptr_copy(pointer, pointer.sub(deleted), 1);
+                        }
+                    }
+                }
+
+                *current = length - deleted;
+            },
+
+            Storage::Heap(vector) => vector.retain(keep),
+        }
+    }
+
+    /// Returns a reference to the value at the given index if it exists.
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        return self.as_slice().get(idx);
+    }
+
+    /// Returns a mutable reference to the value at the given index if it exists.
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        return self.as_mut_slice().get_mut(idx);
+    }
+
+    /// Returns a slice over every value within the `SmallVector`.
+    pub fn as_slice(&self) -> &[T] {
+        match &self.storage {
+            Storage::Inline { buffer, length } => unsafe {
+                return slice::from_raw_parts(buffer.as_ptr() as *const T, *length);
+            },
+
+            Storage::Heap(vector) => return vector.as_slice(),
+        }
+    }
+
+    /// Returns a mutable slice over every value within the `SmallVector`.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.storage {
+            Storage::Inline { buffer, length } => unsafe {
+                return slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut T, *length);
+            },
+
+            Storage::Heap(vector) => return vector.as_mut_slice(),
+        }
+    }
+
+    /// Returns an iterator over references to the values of the `SmallVector`.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        return Iter::new(self.as_slice());
+    }
+
+    /// Returns an iterator over mutable references to the values of the `SmallVector`.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        return IterMut::new(self.as_mut_slice());
+    }
+}
+
+
+/* Private Methods */
+impl<T, const N: usize> SmallVector<T, N> {
+    /// Moves every inline value into a newly allocated `Vector` with room for twice as many values.
+    fn spill(&mut self) {
+        if let Storage::Inline { buffer, length } = &mut self.storage {
+            let mut vector = Vector::with_capacity(N.saturating_mul(2));
+
+            for value in &buffer[.. *length] {
+                unsafe { vector.push(ptr_read(value.as_ptr())); }
+            }
+
+            /* The values now belong to `vector`, so the inline buffer is forgotten without dropping them. */
+            *length = 0;
+            self.storage = Storage::Heap(vector);
+        }
+    }
+}
+
+
+impl<T: PartialEq, const N: usize> SmallVector<T, N> {
+    /// Searches through the `SmallVector` to find a value that matches `finding`, returning its index if found.
+    pub fn search(&self, finding: T) -> Option<usize> {
+        return self.iter().position(|value| *value == finding);
+    }
+}
+
+
+impl<T: Clone, const N: usize> Clone for SmallVector<T, N> {
+    fn clone(&self) -> Self {
+        return self.iter().cloned().collect();
+    }
+}
+
+
+impl<T, const N: usize> Default for SmallVector<T, N> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+
+impl<T, const N: usize> Drop for SmallVector<T, N> {
+    fn drop(&mut self) {
+        /* A spilled `Vector` drops its own values. */
+        if let Storage::Inline { buffer, length } = &mut self.storage {
+            unsafe { drop_in_place(slice_from_raw_parts_mut(buffer.as_mut_ptr() as *mut T, *length)); }
+        }
+    }
+}
+
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SmallVector<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f.debug_list().entries(self.iter()).finish();
+    }
+}
+
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<SmallVector<T, M>> for SmallVector<T, N> {
+    fn eq(&self, other: &SmallVector<T, M>) -> bool {
+        return self.as_slice() == other.as_slice();
+    }
+}
+
+
+impl<T: Eq, const N: usize> Eq for SmallVector<T, N> {  }
+
+
+impl<T: PartialEq, const N: usize> PartialEq<[T]> for SmallVector<T, N> {
+    fn eq(&self, other: &[T]) -> bool {
+        return self.as_slice() == other;
+    }
+}
+
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<[T; M]> for SmallVector<T, N> {
+    fn eq(&self, other: &[T; M]) -> bool {
+        return self.as_slice() == other;
+    }
+}
+
+
+impl<T: Hash, const N: usize> Hash for SmallVector<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+
+impl<T, const N: usize> Deref for SmallVector<T, N> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        return self.as_slice();
+    }
+}
+
+
+impl<T, const N: usize> DerefMut for SmallVector<T, N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        return self.as_mut_slice();
+    }
+}
+
+
+impl<T, I: SliceIndex<[T]>, const N: usize> Index<I> for SmallVector<T, N> {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        return &self.as_slice()[index];
+    }
+}
+
+
+impl<T, I: SliceIndex<[T]>, const N: usize> IndexMut<I> for SmallVector<T, N> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        return &mut self.as_mut_slice()[index];
+    }
+}
+
+
+impl<T, const N: usize> FromIterator<T> for SmallVector<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        return result;
+    }
+}
+
+
+impl<T, const N: usize> Extend<T> for SmallVector<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter { self.push(value); }
+    }
+}
+
+
+impl<T, const N: usize> IntoIterator for SmallVector<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return IntoIter::new(self);
+    }
+}
+
+
+impl<'a, T, const N: usize> IntoIterator for &'a SmallVector<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut SmallVector<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter_mut();
+    }
+}
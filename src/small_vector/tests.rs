@@ -0,0 +1,244 @@
+use super::SmallVector;
+use crate::small_vector;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+
+#[test]
+fn push_inline() {
+    let mut small: SmallVector<i32, 4> = SmallVector::new();
+    assert!(small.is_empty());
+    assert_eq!(small.capacity(), 4);
+
+    for i in 0 .. 4 { small.push(i); }
+    assert_eq!(small.len(), 4);
+    assert!(!small.spilled());
+    assert_eq!(small, [0, 1, 2, 3]);
+}
+
+#[test]
+fn push_spills() {
+    let mut small: SmallVector<String, 2> = SmallVector::new();
+    small.push(String::from("a"));
+    small.push(String::from("b"));
+    small.push(String::from("c"));
+
+    assert!(small.spilled());
+    assert!(small.capacity() >= 3);
+    assert_eq!(small, [String::from("a"), String::from("b"), String::from("c")]);
+
+    for i in 0 .. 100 { small.push(i.to_string()); }
+    assert_eq!(small.len(), 103);
+    assert_eq!(small[102], "99");
+}
+
+#[test]
+fn zero_inline_capacity() {
+    let mut small: SmallVector<i32, 0> = SmallVector::new();
+    assert_eq!(small.pop(), None);
+
+    small.push(1);
+    assert!(small.spilled());
+    assert_eq!(small, [1]);
+}
+
+#[test]
+fn pop() {
+    let mut small: SmallVector<i32, 2> = small_vector![1, 2];
+    assert_eq!(small.pop(), Some(2));
+    assert_eq!(small.pop(), Some(1));
+    assert_eq!(small.pop(), None);
+
+    let mut small: SmallVector<i32, 2> = small_vector![1, 2, 3];
+    assert_eq!(small.pop(), Some(3));
+    assert_eq!(small.pop(), Some(2));
+    assert!(small.spilled());
+}
+
+#[test]
+fn get() {
+    let mut small: SmallVector<i32, 4> = small_vector![1, 2, 3];
+    assert_eq!(small.get(0), Some(&1));
+    assert_eq!(small.get(3), None);
+
+    *small.get_mut(1).unwrap() = 5;
+    assert_eq!(small.get(1), Some(&5));
+}
+
+#[test]
+fn index() {
+    let mut small: SmallVector<i32, 2> = small_vector![1, 2, 3, 4];
+    assert_eq!(small[0], 1);
+    assert_eq!(&small[1 .. 3], &[2, 3]);
+
+    small[3] = 0;
+    assert_eq!(small, [1, 2, 3, 0]);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+    let small: SmallVector<i32, 4> = small_vector![1, 2, 3];
+    let _ = small[3];
+}
+
+#[test]
+fn search() {
+    let inline: SmallVector<i32, 8> = small_vector![1, 2, 3];
+    assert_eq!(inline.search(3), Some(2));
+    assert_eq!(inline.search(4), None);
+
+    let spilled: SmallVector<i32, 1> = small_vector![1, 2, 3];
+    assert_eq!(spilled.search(2), Some(1));
+}
+
+#[test]
+fn insert() {
+    let mut small: SmallVector<i32, 4> = small_vector![1, 3];
+    small.insert(1, 2);
+    small.insert(3, 4);
+    assert!(!small.spilled());
+    assert_eq!(small, [1, 2, 3, 4]);
+
+    small.insert(0, 0);
+    assert!(small.spilled());
+    assert_eq!(small, [0, 1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds() {
+    let mut small: SmallVector<i32, 4> = small_vector![1, 2];
+    small.insert(3, 0);
+}
+
+#[test]
+fn remove() {
+    let mut inline: SmallVector<i32, 4> = small_vector![1, 2, 3];
+    assert_eq!(inline.remove(1), 2);
+    assert_eq!(inline.remove(1), 3);
+    assert_eq!(inline, [1]);
+
+    let mut spilled: SmallVector<i32, 2> = small_vector![1, 2, 3];
+    assert_eq!(spilled.remove(0), 1);
+    assert_eq!(spilled, [2, 3]);
+}
+
+#[test]
+fn truncate() {
+    let rc = Rc::new(());
+
+    let mut inline: SmallVector<Rc<()>, 4> = (0 .. 4).map(|_| rc.clone()).collect();
+    inline.truncate(8);
+    assert_eq!(inline.len(), 4);
+    inline.truncate(1);
+    assert_eq!(Rc::strong_count(&rc), 2);
+
+    let mut spilled: SmallVector<Rc<()>, 2> = (0 .. 4).map(|_| rc.clone()).collect();
+    spilled.truncate(3);
+    assert_eq!(Rc::strong_count(&rc), 5);
+}
+
+#[test]
+fn retain() {
+    let mut inline: SmallVector<i32, 8> = (1 ..= 6).collect();
+    inline.retain(|x| x % 2 == 0);
+    assert_eq!(inline, [2, 4, 6]);
+
+    let mut spilled: SmallVector<i32, 2> = (1 ..= 6).collect();
+    spilled.retain(|x| *x > 3);
+    assert_eq!(spilled, [4, 5, 6]);
+}
+
+#[test]
+fn iter() {
+    let mut small: SmallVector<i32, 2> = (1 ..= 3).collect();
+    for value in &mut small { *value *= 2; }
+
+    let collected: Vec<i32> = small.iter().rev().copied().collect();
+    assert_eq!(collected, vec![6, 4, 2]);
+    assert_eq!(format!("{:?}", small), "[2, 4, 6]");
+}
+
+#[test]
+fn drops_values_once() {
+    let rc = Rc::new(());
+
+    let mut inline: SmallVector<Rc<()>, 4> = SmallVector::new();
+    for _ in 0 .. 3 { inline.push(rc.clone()); }
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    let mut spilled: SmallVector<Rc<()>, 4> = SmallVector::new();
+    for _ in 0 .. 6 { spilled.push(rc.clone()); }
+    assert_eq!(Rc::strong_count(&rc), 10);
+
+    drop(inline);
+    assert_eq!(Rc::strong_count(&rc), 7);
+
+    spilled.clear();
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn into_iter() {
+    let inline: SmallVector<String, 4> = small_vector![String::from("a"), String::from("b"), String::from("c")];
+    let mut iter = inline.into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back().as_deref(), Some("c"));
+    assert_eq!(iter.as_slice(), &[String::from("a"), String::from("b")]);
+    assert_eq!(iter.next().as_deref(), Some("a"));
+    assert_eq!(iter.next().as_deref(), Some("b"));
+    assert_eq!(iter.next(), None);
+
+    let spilled: SmallVector<i32, 2> = small_vector![1, 2, 3];
+    let collected: Vec<i32> = spilled.into_iter().rev().collect();
+    assert_eq!(collected, vec![3, 2, 1]);
+}
+
+#[test]
+fn into_iter_drops_remaining_values() {
+    let rc = Rc::new(());
+
+    let inline: SmallVector<Rc<()>, 8> = (0 .. 6).map(|_| rc.clone()).collect();
+    let mut iter = inline.into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(Rc::strong_count(&rc), 5);
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let spilled: SmallVector<Rc<()>, 2> = (0 .. 6).map(|_| rc.clone()).collect();
+    let mut iter = spilled.into_iter();
+    drop(iter.next());
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn clone() {
+    let inline: SmallVector<String, 2> = small_vector![String::from("a")];
+    assert_eq!(inline.clone(), inline);
+
+    let spilled: SmallVector<String, 1> = small_vector![String::from("a"), String::from("b")];
+    let cloned = spilled.clone();
+    assert!(cloned.spilled());
+    assert_eq!(cloned, spilled);
+}
+
+#[test]
+fn hash() {
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        return hasher.finish();
+    }
+
+    let inline: SmallVector<i32, 4> = small_vector![1, 2, 3];
+    let spilled: SmallVector<i32, 4> = small_vector![1, 2, 3, 4, 5];
+    let mut popped = spilled.clone();
+    popped.truncate(3);
+
+    assert_eq!(hash_of(&inline), hash_of(&popped));
+    assert_ne!(hash_of(&inline), hash_of(&spilled));
+}
@@ -0,0 +1,174 @@
+use super::{TreeMap, key_cmp};
+
+use core::mem::replace as mem_replace;
+use core::ptr::NonNull;
+
+
+/// View into a single entry of a `TreeMap`, which may either be vacant or occupied.
+/// Constructed by `TreeMap::entry`.
+pub enum Entry<'a, K, V> {
+    Vacant(VacantEntry<'a, K, V>),
+    Occupied(OccupiedEntry<'a, K, V>),
+}
+
+
+/// View into a vacant entry of a `TreeMap`, holding the key that will be inserted.
+/// ## Fields:
+/// ```rust
+/// key: K // Key the entry will be inserted with.
+/// map: &'a mut TreeMap<K, V> // Map the entry belongs to.
+/// ```
+pub struct VacantEntry<'a, K, V> {
+    key: K,
+    map: &'a mut TreeMap<K, V>,
+}
+
+
+/// View into an occupied entry of a `TreeMap`.
+/// ## Fields:
+/// ```rust
+/// entry: NonNull<(K, V)> // Key-value pair stored within the Node of the entry.
+/// map: &'a mut TreeMap<K, V> // Map the entry belongs to.
+/// ```
+pub struct OccupiedEntry<'a, K, V> {
+    entry: NonNull<(K, V)>,
+    map: &'a mut TreeMap<K, V>,
+}
+
+
+impl<'a, K: Ord, V> Entry<'a, K, V> {
+    /// Returns a reference to the key of the entry.
+    #[inline]
+    pub fn key(&self) -> &K {
+        return match self {
+            Entry::Vacant(entry) => entry.key(),
+            Entry::Occupied(entry) => entry.key(),
+        };
+    }
+
+    /// Inserts `default` if the entry is vacant, returning a mutable reference to the value of the entry.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<&str, i32> = TreeMap::new();
+    /// *map.entry("Key").or_insert(1) += 1;
+    /// assert_eq!(map["Key"], 2);
+    /// ```
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        return self.or_insert_with(|| default);
+    }
+
+    /// Inserts the result of `default` if the entry is vacant, returning a mutable reference to the value of the entry.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        return match self {
+            Entry::Vacant(entry) => entry.insert(default()),
+            Entry::Occupied(entry) => entry.into_mut(),
+        };
+    }
+
+    /// Calls `f` with a mutable reference to the value if the entry is occupied, returning the entry afterwards.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<&str, i32> = TreeMap::new();
+    /// map.entry("Key").and_modify(|v| *v += 1).or_insert(0);
+    /// map.entry("Key").and_modify(|v| *v += 1).or_insert(0);
+    /// assert_eq!(map["Key"], 1);
+    /// ```
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self { f(entry.get_mut()); }
+        return self;
+    }
+}
+
+
+impl<'a, K: Ord, V: Default> Entry<'a, K, V> {
+    /// Inserts `V::default()` if the entry is vacant, returning a mutable reference to the value of the entry.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V {
+        return self.or_insert_with(V::default);
+    }
+}
+
+
+impl<'a, K: Ord, V> VacantEntry<'a, K, V> {
+    pub(super) fn new(key: K, map: &'a mut TreeMap<K, V>) -> Self {
+        return Self { key, map };
+    }
+
+    /// Returns a reference to the key the entry will be inserted with.
+    #[inline(always)]
+    pub fn key(&self) -> &K {
+        return &self.key;
+    }
+
+    /// Takes ownership of the key the entry would have been inserted with.
+    #[inline(always)]
+    pub fn into_key(self) -> K {
+        return self.key;
+    }
+
+    /// Inserts `value` into the `TreeMap` with the key of the entry, returning a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let (entry, _) = self.map.tree.insert_by((self.key, value), key_cmp);
+        return &mut entry.1;
+    }
+}
+
+
+impl<'a, K: Ord, V> OccupiedEntry<'a, K, V> {
+    pub(super) fn new(entry: NonNull<(K, V)>, map: &'a mut TreeMap<K, V>) -> Self {
+        return Self { entry, map };
+    }
+
+    /// Returns a reference to the key of the entry.
+    #[inline(always)]
+    pub fn key(&self) -> &K {
+        return unsafe { &self.entry.as_ref().0 };
+    }
+
+    /// Returns a reference to the value of the entry.
+    #[inline(always)]
+    pub fn get(&self) -> &V {
+        return unsafe { &self.entry.as_ref().1 };
+    }
+
+    /// Returns a mutable reference to the value of the entry.
+    #[inline(always)]
+    pub fn get_mut(&mut self) -> &mut V {
+        return unsafe { &mut self.entry.as_mut().1 };
+    }
+
+    /// Converts the entry into a mutable reference to its value, bound to the lifetime of the `TreeMap`.
+    #[inline(always)]
+    pub fn into_mut(self) -> &'a mut V {
+        return unsafe { &mut (*self.entry.as_ptr()).1 };
+    }
+
+    /// Replaces the value of the entry with `value`, returning the old value.
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        return mem_replace(self.get_mut(), value);
+    }
+
+    /// Removes the entry from the `TreeMap`, returning its value.
+    #[inline]
+    pub fn remove(self) -> V {
+        return self.remove_entry().1;
+    }
+
+    /// Removes the entry from the `TreeMap`, returning its key and value.
+    pub fn remove_entry(self) -> (K, V) {
+        let target = self.entry;
+
+        /*
+            SAFETY: The `Node` of the entry is matched by address, so the key is only read
+            while comparing against the other `Node`s on the way down, never after its own `Node` is borrowed.
+        */
+        return self.map.tree.take_identical(target, |(k, _)| unsafe { target.as_ref().0.cmp(k) })
+            .expect("Occupied entry must be present within the map.");
+    }
+}
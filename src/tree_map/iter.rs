@@ -0,0 +1,348 @@
+use crate::binary_tree::{self, RawIter, RawRange};
+
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::option::Option;
+
+
+/// Struct for iterating over the entries of a `TreeMap`, sorted by key.
+pub struct Iter<'a, K, V> {
+    inner: binary_tree::Iter<'a, (K, V)>,
+}
+
+
+/// Struct for iterating over the entries of a `TreeMap` sorted by key, with mutable references to the values.
+pub struct IterMut<'a, K, V> {
+    raw: RawIter<(K, V)>,
+    marker: PhantomData<&'a mut (K, V)>,
+}
+
+
+/// Struct for iterating over the keys of a `TreeMap` in ascending order.
+pub struct Keys<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+
+/// Struct for iterating over the values of a `TreeMap`, sorted by their keys.
+pub struct Values<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+
+/// Struct for iterating over mutable references to the values of a `TreeMap`, sorted by their keys.
+pub struct ValuesMut<'a, K, V> {
+    inner: IterMut<'a, K, V>,
+}
+
+
+/// Struct for iterating over the entries of a `TreeMap` whose keys lie within a range, sorted by key.
+pub struct Range<'a, K, V> {
+    raw: RawRange<(K, V)>,
+    marker: PhantomData<&'a (K, V)>,
+}
+
+
+/// Struct for iterating over the entries of a `TreeMap` whose keys lie within a range, with mutable references to the values.
+pub struct RangeMut<'a, K, V> {
+    raw: RawRange<(K, V)>,
+    marker: PhantomData<&'a mut (K, V)>,
+}
+
+
+/// Struct for converting a `TreeMap` into an `Iterator` over its entries, sorted by key.
+pub struct IntoIter<K, V> {
+    inner: binary_tree::IntoIter<(K, V)>,
+}
+
+
+impl<'a, K, V> Iter<'a, K, V> {
+    pub(super) fn new(inner: binary_tree::Iter<'a, (K, V)>) -> Self {
+        return Self { inner };
+    }
+}
+
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.inner.next().map(|(k, v)| (k, v));
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.inner.next_back().map(|(k, v)| (k, v));
+    }
+}
+
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {  }
+impl<'a, K, V> FusedIterator for Iter<'a, K, V> {  }
+
+
+impl<'a, K, V> Clone for Iter<'a, K, V> {
+    fn clone(&self) -> Self {
+        return Self { inner: self.inner.clone() };
+    }
+}
+
+
+impl<'a, K, V> IterMut<'a, K, V> {
+    pub(super) fn new(raw: RawIter<(K, V)>) -> Self {
+        return Self { raw, marker: PhantomData };
+    }
+}
+
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.raw.next().map(|ptr| unsafe {
+            let (k, v) = &mut (*ptr.as_ptr()).data;
+            (&*k, v)
+        });
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.raw.size_hint();
+    }
+}
+
+
+impl<'a, K, V> DoubleEndedIterator for IterMut<'a, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.raw.next_back().map(|ptr| unsafe {
+            let (k, v) = &mut (*ptr.as_ptr()).data;
+            (&*k, v)
+        });
+    }
+}
+
+
+impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {  }
+impl<'a, K, V> FusedIterator for IterMut<'a, K, V> {  }
+
+
+impl<'a, K, V> Keys<'a, K, V> {
+    pub(super) fn new(inner: Iter<'a, K, V>) -> Self {
+        return Self { inner };
+    }
+}
+
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.inner.next().map(|(k, _)| k);
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<'a, K, V> DoubleEndedIterator for Keys<'a, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.inner.next_back().map(|(k, _)| k);
+    }
+}
+
+
+impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {  }
+impl<'a, K, V> FusedIterator for Keys<'a, K, V> {  }
+
+
+impl<'a, K, V> Values<'a, K, V> {
+    pub(super) fn new(inner: Iter<'a, K, V>) -> Self {
+        return Self { inner };
+    }
+}
+
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.inner.next().map(|(_, v)| v);
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<'a, K, V> DoubleEndedIterator for Values<'a, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.inner.next_back().map(|(_, v)| v);
+    }
+}
+
+
+impl<'a, K, V> ExactSizeIterator for Values<'a, K, V> {  }
+impl<'a, K, V> FusedIterator for Values<'a, K, V> {  }
+
+
+impl<'a, K, V> ValuesMut<'a, K, V> {
+    pub(super) fn new(inner: IterMut<'a, K, V>) -> Self {
+        return Self { inner };
+    }
+}
+
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.inner.next().map(|(_, v)| v);
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<'a, K, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.inner.next_back().map(|(_, v)| v);
+    }
+}
+
+
+impl<'a, K, V> ExactSizeIterator for ValuesMut<'a, K, V> {  }
+impl<'a, K, V> FusedIterator for ValuesMut<'a, K, V> {  }
+
+
+impl<'a, K, V> Range<'a, K, V> {
+    pub(super) fn new(raw: RawRange<(K, V)>) -> Self {
+        return Self { raw, marker: PhantomData };
+    }
+}
+
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.raw.next().map(|ptr| unsafe {
+            let (k, v) = &(*ptr.as_ptr()).data;
+            (k, v)
+        });
+    }
+}
+
+
+impl<'a, K, V> DoubleEndedIterator for Range<'a, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.raw.next_back().map(|ptr| unsafe {
+            let (k, v) = &(*ptr.as_ptr()).data;
+            (k, v)
+        });
+    }
+}
+
+
+impl<'a, K, V> FusedIterator for Range<'a, K, V> {  }
+
+
+impl<'a, K, V> Clone for Range<'a, K, V> {
+    fn clone(&self) -> Self {
+        return Self { raw: self.raw.clone(), marker: PhantomData };
+    }
+}
+
+
+impl<'a, K, V> RangeMut<'a, K, V> {
+    pub(super) fn new(raw: RawRange<(K, V)>) -> Self {
+        return Self { raw, marker: PhantomData };
+    }
+}
+
+
+impl<'a, K, V> Iterator for RangeMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.raw.next().map(|ptr| unsafe {
+            let (k, v) = &mut (*ptr.as_ptr()).data;
+            (&*k, v)
+        });
+    }
+}
+
+
+impl<'a, K, V> DoubleEndedIterator for RangeMut<'a, K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.raw.next_back().map(|ptr| unsafe {
+            let (k, v) = &mut (*ptr.as_ptr()).data;
+            (&*k, v)
+        });
+    }
+}
+
+
+impl<'a, K, V> FusedIterator for RangeMut<'a, K, V> {  }
+
+
+impl<K, V> IntoIter<K, V> {
+    pub(super) fn new(inner: binary_tree::IntoIter<(K, V)>) -> Self {
+        return Self { inner };
+    }
+}
+
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.inner.next();
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<K, V> DoubleEndedIterator for IntoIter<K, V> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.inner.next_back();
+    }
+}
+
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {  }
+impl<K, V> FusedIterator for IntoIter<K, V> {  }
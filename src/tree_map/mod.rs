@@ -0,0 +1,390 @@
+#[cfg(test)]
+mod tests;
+
+mod entry;
+pub use entry::{Entry, VacantEntry, OccupiedEntry};
+
+mod iter;
+pub use iter::{Iter, IterMut, Keys, Values, ValuesMut, Range, RangeMut, IntoIter};
+
+use crate::binary_tree::{BinaryTree, before_start, after_end};
+
+use core::iter::FromIterator;
+use core::option::Option;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::ops::{Index, RangeBounds};
+use core::ptr::NonNull;
+use core::mem::replace as mem_replace;
+use core::fmt;
+
+
+/// Ordered map built upon the `Node`s of a `BinaryTree`, mirroring `std::collections::BTreeMap`.
+/// Each `Node` stores a key alongside its value, and the Tree is ordered by the keys alone.
+/// 
+/// ## Fields:
+/// ```rust
+/// tree: BinaryTree<(K, V)> // Balanced Tree of key-value pairs.
+/// ```
+pub struct TreeMap<K, V> {
+    tree: BinaryTree<(K, V)>,
+}
+
+
+/// Orders two key-value pairs by their keys.
+#[inline(always)]
+fn key_cmp<K: Ord, V>(a: &(K, V), b: &(K, V)) -> Ordering {
+    return a.0.cmp(&b.0);
+}
+
+
+impl<K, V> TreeMap<K, V> {
+    /// Constructs a new empty `TreeMap`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<&str, i32> = TreeMap::new();
+    /// map.insert("One", 1);
+    /// assert_eq!(map.get("One"), Some(&1));
+    /// ```
+    #[inline(always)]
+    pub const fn new() -> Self {
+        return Self { tree: BinaryTree::new() };
+    }
+
+    /// Returns the amount of entries within the `TreeMap`.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        return self.tree.len();
+    }
+
+    /// Returns a `bool` that determines if the `TreeMap` is empty.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        return self.tree.is_empty();
+    }
+
+    /// Clears the `TreeMap`, removing every entry.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.tree.clear();
+    }
+
+    /// Returns references to the entry with the smallest key within the `TreeMap`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<i32, &str> = TreeMap::new();
+    /// map.insert(2, "Two");
+    /// map.insert(1, "One");
+    /// assert_eq!(map.first_key_value(), Some((&1, &"One")));
+    /// ```
+    #[inline]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        return self.tree.first().map(|(k, v)| (k, v));
+    }
+
+    /// Returns references to the entry with the largest key within the `TreeMap`.
+    #[inline]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        return self.tree.last().map(|(k, v)| (k, v));
+    }
+
+    /// Removes the entry with the smallest key from the `TreeMap` and returns it.
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        return self.tree.pop_first();
+    }
+
+    /// Removes the entry with the largest key from the `TreeMap` and returns it.
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        return self.tree.pop_last();
+    }
+
+    /// Returns an `Iterator` over the entries of the `TreeMap`, sorted by key.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<i32, char> = TreeMap::new();
+    /// map.insert(2, 'b');
+    /// map.insert(1, 'a');
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&1, &'a'), (&2, &'b')]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        return Iter::new(self.tree.iter());
+    }
+
+    /// Returns an `Iterator` over the entries of the `TreeMap` sorted by key, with mutable references to the values.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        return IterMut::new(self.tree.raw_iter());
+    }
+
+    /// Returns an `Iterator` over the keys of the `TreeMap` in ascending order.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<i32, char> = TreeMap::new();
+    /// map.insert(2, 'b');
+    /// map.insert(1, 'a');
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        return Keys::new(self.iter());
+    }
+
+    /// Returns an `Iterator` over the values of the `TreeMap`, sorted by their keys.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<i32, char> = TreeMap::new();
+    /// map.insert(2, 'b');
+    /// map.insert(1, 'a');
+    /// assert_eq!(map.values().collect::<Vec<_>>(), vec![&'a', &'b']);
+    /// ```
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        return Values::new(self.iter());
+    }
+
+    /// Returns an `Iterator` over mutable references to the values of the `TreeMap`, sorted by their keys.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        return ValuesMut::new(self.iter_mut());
+    }
+}
+
+
+impl<K: Ord, V> TreeMap<K, V> {
+    /// Inserts a key-value pair into the `TreeMap`.
+    /// If the key was already present its value is replaced and the old value is returned, the key itself is kept.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<&str, i32> = TreeMap::new();
+    /// assert_eq!(map.insert("Key", 1), None);
+    /// assert_eq!(map.insert("Key", 2), Some(1));
+    /// assert_eq!(map["Key"], 2);
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (entry, rejected) = self.tree.insert_by((key, value), key_cmp);
+        return rejected.map(|(_, value)| mem_replace(&mut entry.1, value));
+    }
+
+    /// Returns a reference to the value corresponding to `key`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<i32, &str> = TreeMap::new();
+    /// map.insert(1, "One");
+    /// assert_eq!(map.get(&1), Some(&"One"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    #[inline]
+    pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        return self.get_key_value(key).map(|(_, v)| v);
+    }
+
+    /// Returns references to the key and value corresponding to `key`.
+    #[inline]
+    pub fn get_key_value<Q: ?Sized + Ord>(&self, key: &Q) -> Option<(&K, &V)> where K: Borrow<Q> {
+        return self.tree.get_by(|(k, _)| key.cmp(k.borrow())).map(|(k, v)| (k, v));
+    }
+
+    /// Returns a mutable reference to the value corresponding to `key`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<i32, &str> = TreeMap::new();
+    /// map.insert(1, "One");
+    /// *map.get_mut(&1).unwrap() = "Uno";
+    /// assert_eq!(map[&1], "Uno");
+    /// ```
+    #[inline]
+    pub fn get_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+        return self.tree.get_mut_by(|(k, _)| key.cmp(k.borrow())).map(|(_, v)| v);
+    }
+
+    /// Returns a `bool` that determines if the `TreeMap` contains an entry for `key`.
+    #[inline]
+    pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool where K: Borrow<Q> {
+        return self.get_key_value(key).is_some();
+    }
+
+    /// Removes the entry for `key` from the `TreeMap`, returning its value if it was present.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<i32, &str> = TreeMap::new();
+    /// map.insert(1, "One");
+    /// assert_eq!(map.remove(&1), Some("One"));
+    /// assert_eq!(map.remove(&1), None);
+    /// ```
+    #[inline]
+    pub fn remove<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
+        return self.remove_entry(key).map(|(_, v)| v);
+    }
+
+    /// Removes the entry for `key` from the `TreeMap`, returning the stored key and value if it was present.
+    #[inline]
+    pub fn remove_entry<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<(K, V)> where K: Borrow<Q> {
+        return self.tree.take_by(|(k, _)| key.cmp(k.borrow()));
+    }
+
+    /// Returns an `Iterator` over the entries of the `TreeMap` whose keys lie within `range`, sorted by key.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let map: TreeMap<i32, char> = vec![(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+    /// assert_eq!(map.range(2 ..).collect::<Vec<_>>(), vec![(&2, &'b'), (&3, &'c')]);
+    /// ```
+    pub fn range<Q: ?Sized + Ord, R: RangeBounds<Q>>(&self, range: R) -> Range<'_, K, V> where K: Borrow<Q> {
+        return Range::new(self.tree.raw_range(
+            |(k, _)| before_start(range.start_bound(), |bound| k.borrow().cmp(bound)),
+            |(k, _)| after_end(range.end_bound(), |bound| k.borrow().cmp(bound)),
+        ));
+    }
+
+    /// Returns an `Iterator` over the entries of the `TreeMap` whose keys lie within `range` sorted by key,
+    /// with mutable references to the values.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<i32, i32> = vec![(1, 1), (2, 2), (3, 3)].into_iter().collect();
+    /// for (_, v) in map.range_mut(.. 3) { *v *= 10; }
+    /// assert_eq!(map.values().collect::<Vec<_>>(), vec![&10, &20, &3]);
+    /// ```
+    pub fn range_mut<Q: ?Sized + Ord, R: RangeBounds<Q>>(&mut self, range: R) -> RangeMut<'_, K, V> where K: Borrow<Q> {
+        return RangeMut::new(self.tree.raw_range(
+            |(k, _)| before_start(range.start_bound(), |bound| k.borrow().cmp(bound)),
+            |(k, _)| after_end(range.end_bound(), |bound| k.borrow().cmp(bound)),
+        ));
+    }
+
+    /// Removes every entry whose key lies within `range`, returning an `Iterator` over them sorted by key.
+    /// The entries are removed even if the returned `Iterator` is not consumed.
+    pub fn drain_range<Q: ?Sized + Ord, R: RangeBounds<Q>>(&mut self, range: R) -> IntoIter<K, V> where K: Borrow<Q> {
+        let drained = self.tree.extract_by(
+            |(k, _)| before_start(range.start_bound(), |bound| k.borrow().cmp(bound)),
+            |(k, _)| after_end(range.end_bound(), |bound| k.borrow().cmp(bound)),
+        );
+
+        return IntoIter::new(drained.into_iter());
+    }
+
+    /// Splits the `TreeMap` in two, returning a new map containing every entry whose key is greater than or equal to `key`.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut map: TreeMap<i32, char> = vec![(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+    /// let upper = map.split_off(&2);
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), vec![&1]);
+    /// assert_eq!(upper.keys().collect::<Vec<_>>(), vec![&2, &3]);
+    /// ```
+    pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self where K: Borrow<Q> {
+        return Self { tree: self.tree.extract_by(|(k, _)| k.borrow() < key, |_| false) };
+    }
+
+    /// Returns the `Entry` for `key`, allowing in-place manipulation of its value whether it is present or not.
+    /// 
+    /// ## Example:
+    /// ```rust
+    /// let mut counts: TreeMap<&str, i32> = TreeMap::new();
+    /// for word in ["a", "b", "a"] { *counts.entry(word).or_insert(0) += 1; }
+    /// assert_eq!(counts["a"], 2);
+    /// assert_eq!(counts["b"], 1);
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let found = self.tree.get_mut_by(|(k, _)| key.cmp(k)).map(NonNull::from);
+
+        return match found {
+            Some(entry) => Entry::Occupied(OccupiedEntry::new(entry, self)),
+            None => Entry::Vacant(VacantEntry::new(key, self)),
+        };
+    }
+}
+
+
+impl<K, V> Default for TreeMap<K, V> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for TreeMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f.debug_map().entries(self.iter()).finish();
+    }
+}
+
+
+impl<K: PartialEq, V: PartialEq> PartialEq for TreeMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.tree == other.tree;
+    }
+}
+
+
+impl<K: Eq, V: Eq> Eq for TreeMap<K, V> {  }
+
+
+impl<K: Ord + Borrow<Q>, Q: ?Sized + Ord, V> Index<&Q> for TreeMap<K, V> {
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        return self.get(key).expect("No entry found for key.");
+    }
+}
+
+
+impl<K: Ord, V> FromIterator<(K, V)> for TreeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        return result;
+    }
+}
+
+
+impl<K: Ord, V> Extend<(K, V)> for TreeMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter { self.insert(k, v); }
+    }
+}
+
+
+impl<K, V> IntoIterator for TreeMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return IntoIter::new(self.tree.into_iter());
+    }
+}
+
+
+impl<'a, K, V> IntoIterator for &'a TreeMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+
+impl<'a, K, V> IntoIterator for &'a mut TreeMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter_mut();
+    }
+}
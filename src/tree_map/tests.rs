@@ -0,0 +1,210 @@
+use super::{TreeMap, Entry};
+use std::ops::Bound;
+
+
+fn sample() -> TreeMap<i32, &'static str> {
+    return vec![(3, "Three"), (1, "One"), (4, "Four"), (2, "Two"), (5, "Five")].into_iter().collect();
+}
+
+#[test]
+fn insert() {
+    let mut map = TreeMap::new();
+    assert_eq!(map.insert("B", 2), None);
+    assert_eq!(map.insert("A", 1), None);
+    assert_eq!(map.insert("B", 20), Some(2));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["B"], 20);
+}
+
+#[test]
+fn get() {
+    let map = sample();
+    assert_eq!(map.get(&3), Some(&"Three"));
+    assert_eq!(map.get(&6), None);
+    assert_eq!(map.get_key_value(&1), Some((&1, &"One")));
+    assert!(map.contains_key(&5));
+    assert!(!map.contains_key(&0));
+}
+
+#[test]
+fn get_borrowed() {
+    let mut map: TreeMap<String, i32> = TreeMap::new();
+    map.insert(String::from("Key"), 1);
+    assert_eq!(map.get("Key"), Some(&1));
+    assert_eq!(map["Key"], 1);
+}
+
+#[test]
+fn get_mut() {
+    let mut map = sample();
+    *map.get_mut(&2).unwrap() = "Deux";
+    assert_eq!(map[&2], "Deux");
+    assert_eq!(map.get_mut(&9), None);
+}
+
+#[test]
+fn remove() {
+    let mut map = sample();
+    assert_eq!(map.remove(&3), Some("Three"));
+    assert_eq!(map.remove(&3), None);
+    assert_eq!(map.remove_entry(&1), Some((1, "One")));
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![2, 4, 5]);
+}
+
+#[test]
+fn first_last() {
+    let mut map = sample();
+    assert_eq!(map.first_key_value(), Some((&1, &"One")));
+    assert_eq!(map.last_key_value(), Some((&5, &"Five")));
+    assert_eq!(map.pop_first(), Some((1, "One")));
+    assert_eq!(map.pop_last(), Some((5, "Five")));
+    assert_eq!(map.len(), 3);
+}
+
+#[test]
+fn entry() {
+    let mut map: TreeMap<&str, i32> = TreeMap::new();
+    for word in ["a", "b", "a", "c", "a"] { *map.entry(word).or_insert(0) += 1; }
+    assert_eq!(map.iter().collect::<Vec<_>>(), vec![(&"a", &3), (&"b", &1), (&"c", &1)]);
+
+    map.entry("b").and_modify(|v| *v *= 10).or_default();
+    map.entry("d").and_modify(|v| *v *= 10).or_default();
+    assert_eq!(map["b"], 10);
+    assert_eq!(map["d"], 0);
+    assert_eq!(map.entry("e").key(), &"e");
+}
+
+#[test]
+fn occupied_entry() {
+    let mut map = sample();
+
+    match map.entry(2) {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.key(), &2);
+            assert_eq!(entry.insert("Deux"), "Two");
+            assert_eq!(entry.get(), &"Deux");
+            assert_eq!(entry.remove_entry(), (2, "Deux"));
+        },
+        Entry::Vacant(_) => panic!("Entry should be occupied."),
+    }
+
+    assert_eq!(map.len(), 4);
+    assert!(!map.contains_key(&2));
+}
+
+#[test]
+fn occupied_entry_remove_every_position() {
+    /* Removes the root, inner Nodes with two children and leaves through their entries. */
+    for key in 1 ..= 15 {
+        let mut map: TreeMap<i32, String> = (1 ..= 15).map(|k| (k, k.to_string())).collect();
+
+        match map.entry(key) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), (key, key.to_string())),
+            Entry::Vacant(_) => panic!("Entry should be occupied."),
+        }
+
+        assert_eq!(map.len(), 14);
+        assert!(!map.contains_key(&key));
+        assert!(map.keys().copied().eq((1 ..= 15).filter(|&k| k != key)));
+    }
+}
+
+#[test]
+fn vacant_entry() {
+    let mut map = sample();
+
+    match map.entry(6) {
+        Entry::Vacant(entry) => { *entry.insert("Six") = "Sechs"; },
+        Entry::Occupied(_) => panic!("Entry should be vacant."),
+    }
+
+    assert_eq!(map[&6], "Sechs");
+    assert_eq!(map.len(), 6);
+}
+
+#[test]
+fn iter() {
+    let map = sample();
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(map.values().rev().copied().collect::<Vec<_>>(), vec!["Five", "Four", "Three", "Two", "One"]);
+    assert_eq!(map.iter().len(), 5);
+    assert_eq!((&map).into_iter().next(), Some((&1, &"One")));
+}
+
+#[test]
+fn iter_mut() {
+    let mut map: TreeMap<i32, i32> = (0 .. 5).map(|x| (x, x)).collect();
+    for (k, v) in map.iter_mut() { *v += k; }
+    for v in map.values_mut() { *v += 1; }
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![1, 3, 5, 7, 9]);
+}
+
+#[test]
+fn into_iter() {
+    let map = sample();
+    assert_eq!(map.into_iter().map(|(k, _)| k).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn eq() {
+    assert_eq!(sample(), sample());
+
+    let mut other = sample();
+    other.insert(1, "Uno");
+    assert_ne!(sample(), other);
+}
+
+#[test]
+fn debug() {
+    let map: TreeMap<i32, char> = vec![(2, 'b'), (1, 'a')].into_iter().collect();
+    assert_eq!(format!("{:?}", map), "{1: 'a', 2: 'b'}");
+}
+
+#[test]
+fn range() {
+    let map = sample();
+    assert_eq!(map.range(2 .. 4).map(|(k, _)| *k).collect::<Vec<_>>(), vec![2, 3]);
+    assert_eq!(map.range(4 ..).rev().map(|(k, _)| *k).collect::<Vec<_>>(), vec![5, 4]);
+    assert_eq!(map.range(.. 0).next(), None);
+
+    let strings: TreeMap<String, i32> = vec![(String::from("a"), 1), (String::from("c"), 3)].into_iter().collect();
+    assert_eq!(strings.range::<str, _>((Bound::Included("b"), Bound::Unbounded)).count(), 1);
+}
+
+#[test]
+fn range_mut() {
+    let mut map: TreeMap<i32, i32> = (0 .. 10).map(|x| (x, x)).collect();
+    for (_, v) in map.range_mut(3 ..= 5) { *v = 0; }
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![0, 1, 2, 0, 0, 0, 6, 7, 8, 9]);
+}
+
+#[test]
+fn drain_range() {
+    let mut map = sample();
+    assert_eq!(map.drain_range(2 ..= 3).collect::<Vec<_>>(), vec![(2, "Two"), (3, "Three")]);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![1, 4, 5]);
+}
+
+#[test]
+fn split_off() {
+    let mut map = sample();
+    let upper = map.split_off(&3);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(upper.keys().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+    assert_eq!((map.len(), upper.len()), (2, 3));
+}
+
+#[test]
+fn drop_values() {
+    let value = std::rc::Rc::new(());
+
+    let mut map = TreeMap::new();
+    for x in 0 .. 100 { map.insert(x, value.clone()); }
+    drop(map.insert(0, value.clone()));
+    drop(map.remove(&1));
+    assert_eq!(std::rc::Rc::strong_count(&value), 100);
+
+    map.clear();
+    assert_eq!(std::rc::Rc::strong_count(&value), 1);
+}
@@ -0,0 +1,88 @@
+use super::{Vector, RawAlloc, Global};
+
+use core::ptr::{drop_in_place, slice_from_raw_parts_mut, copy as ptr_copy, read as ptr_read};
+use core::iter::FusedIterator;
+use core::option::Option;
+
+
+/// Struct for draining a range of values out of a `Vector`, created by `Vector::drain`.
+/// While the `Drain` is alive the `Vector` only claims the values before the range,
+/// the values after it are shifted back into place once the `Drain` is dropped.
+/// 
+/// ## Fields:
+/// ```rust
+/// vector: &'a mut Vector<T, A> // Vector being drained, its length is the start of the range.
+/// front: usize // Index of the next value yielded from the front.
+/// back: usize // Index after the next value yielded from the back.
+/// tail: usize // Index of the first value after the range.
+/// tail_length: usize // Amount of values after the range.
+/// ```
+pub struct Drain<'a, T, A: RawAlloc = Global> {
+    vector: &'a mut Vector<T, A>,
+    front: usize,
+    back: usize,
+    tail: usize,
+    tail_length: usize,
+}
+
+
+impl<'a, T, A: RawAlloc> Drain<'a, T, A> {
+    pub(super) fn new(vector: &'a mut Vector<T, A>, start: usize, end: usize) -> Self {
+        let tail_length = vector.length - end;
+        vector.length = start;
+
+        return Self {
+            vector,
+            front: start,
+            back: end,
+            tail: end,
+            tail_length,
+        };
+    }
+}
+
+
+impl<'a, T, A: RawAlloc> Iterator for Drain<'a, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None; }
+
+        self.front += 1;
+        unsafe { return Some(ptr_read(self.vector.ptr.as_ptr().add(self.front - 1))); }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        return (remaining, Some(remaining));
+    }
+}
+
+
+impl<'a, T, A: RawAlloc> DoubleEndedIterator for Drain<'a, T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None; }
+
+        self.back -= 1;
+        unsafe { return Some(ptr_read(self.vector.ptr.as_ptr().add(self.back))); }
+    }
+}
+
+
+impl<'a, T, A: RawAlloc> ExactSizeIterator for Drain<'a, T, A> {  }
+impl<'a, T, A: RawAlloc> FusedIterator for Drain<'a, T, A> {  }
+
+
+impl<'a, T, A: RawAlloc> Drop for Drain<'a, T, A> {
+    fn drop(&mut self) {
+        unsafe {
+            let pointer = self.vector.ptr.as_ptr();
+
+            drop_in_place(slice_from_raw_parts_mut(pointer.add(self.front), self.back - self.front));
+            ptr_copy(pointer.add(self.tail), pointer.add(self.vector.length), self.tail_length);
+        }
+
+        self.vector.length += self.tail_length;
+    }
+}
@@ -0,0 +1,186 @@
+use super::{Vector, RawAlloc, Global};
+
+use core::ptr::{drop_in_place, slice_from_raw_parts_mut, read as ptr_read};
+use core::iter::FusedIterator;
+use core::option::Option;
+use core::slice;
+
+
+/// Struct for iterating over references to the values of a `Vector`, created by `Vector::iter`.
+///
+/// ## Fields:
+/// ```rust
+/// inner: slice::Iter<'a, T> // Iterator over the initialized values of the Vector.
+/// ```
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    inner: slice::Iter<'a, T>,
+}
+
+
+/// Struct for iterating over mutable references to the values of a `Vector`, created by `Vector::iter_mut`.
+///
+/// ## Fields:
+/// ```rust
+/// inner: slice::IterMut<'a, T> // Iterator over the initialized values of the Vector.
+/// ```
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    inner: slice::IterMut<'a, T>,
+}
+
+
+/// Struct for iterating over the values of a `Vector` by value, created by `Vector::into_iter`.
+/// The buffer is owned by a `Vector` whose length is zero, so it is freed once the `IntoIter` is dropped.
+///
+/// ## Fields:
+/// ```rust
+/// vector: Vector<T, A> // Vector owning the buffer, its length is always zero.
+/// front: usize // Index of the next value yielded from the front.
+/// back: usize // Index after the next value yielded from the back.
+/// ```
+pub struct IntoIter<T, A: RawAlloc = Global> {
+    vector: Vector<T, A>,
+    front: usize,
+    back: usize,
+}
+
+
+impl<'a, T> Iter<'a, T> {
+    pub(super) fn new(values: &'a [T]) -> Self {
+        return Self { inner: values.iter() };
+    }
+
+    /// Returns the values that have not been yielded yet as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &'a [T] {
+        return self.inner.as_slice();
+    }
+}
+
+
+impl<'a, T> IterMut<'a, T> {
+    pub(super) fn new(values: &'a mut [T]) -> Self {
+        return Self { inner: values.iter_mut() };
+    }
+
+    /// Consumes the `IterMut`, returning the values that have not been yielded yet as a mutable slice.
+    #[inline(always)]
+    pub fn into_slice(self) -> &'a mut [T] {
+        return self.inner.into_slice();
+    }
+}
+
+
+impl<T, A: RawAlloc> IntoIter<T, A> {
+    pub(super) fn new(mut vector: Vector<T, A>) -> Self {
+        let back = vector.length;
+        vector.length = 0;
+
+        return Self { vector, front: 0, back };
+    }
+
+    /// Returns the values that have not been yielded yet as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { return slice::from_raw_parts(self.vector.ptr.as_ptr().add(self.front), self.back - self.front); }
+    }
+
+    /// Returns the values that have not been yielded yet as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { return slice::from_raw_parts_mut(self.vector.ptr.as_ptr().add(self.front), self.back - self.front); }
+    }
+}
+
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.inner.next();
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.inner.next_back();
+    }
+}
+
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {  }
+impl<'a, T> FusedIterator for Iter<'a, T> {  }
+
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        return self.inner.next();
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        return self.inner.next_back();
+    }
+}
+
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {  }
+impl<'a, T> FusedIterator for IterMut<'a, T> {  }
+
+
+impl<T, A: RawAlloc> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None; }
+
+        self.front += 1;
+        unsafe { return Some(ptr_read(self.vector.ptr.as_ptr().add(self.front - 1))); }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        return (remaining, Some(remaining));
+    }
+}
+
+
+impl<T, A: RawAlloc> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None; }
+
+        self.back -= 1;
+        unsafe { return Some(ptr_read(self.vector.ptr.as_ptr().add(self.back))); }
+    }
+}
+
+
+impl<T, A: RawAlloc> ExactSizeIterator for IntoIter<T, A> {  }
+impl<T, A: RawAlloc> FusedIterator for IntoIter<T, A> {  }
+
+
+impl<T, A: RawAlloc> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        /* `self.vector` has a length of 0 and only frees the buffer, so the values between `front` and `back` are dropped here. */
+        unsafe { drop_in_place(slice_from_raw_parts_mut(self.vector.ptr.as_ptr().add(self.front), self.back - self.front)); }
+    }
+}
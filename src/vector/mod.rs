@@ -1,9 +1,25 @@
-use std::slice::from_raw_parts_mut as slice_from_raw_parts_mut;
-use std::ptr::{NonNull, drop_in_place};
-use std::mem::{size_of, align_of};
-use std::ops::{Index, IndexMut};
-use std::option::Option;
-use std::alloc;
+#[cfg(test)]
+mod tests;
+
+mod drain;
+pub use drain::Drain;
+
+mod iter;
+pub use iter::{Iter, IterMut, IntoIter};
+
+mod raw_alloc;
+pub use raw_alloc::{RawAlloc, Global};
+
+use core::ptr::{NonNull, drop_in_place, slice_from_raw_parts_mut};
+use core::ptr::{copy as ptr_copy, copy_nonoverlapping as ptr_copy_nonoverlapping, read as ptr_read};
+use core::slice::{self, SliceIndex};
+use core::ops::{Index, IndexMut, Deref, DerefMut, RangeBounds, Bound};
+use core::borrow::{Borrow, BorrowMut};
+use core::mem::{size_of, align_of};
+use core::iter::FromIterator;
+use core::option::Option;
+use alloc::alloc::{Layout, handle_alloc_error};
+use core::fmt;
 
 
 /// Shorthand Syntax for creating a new `Vector`.
@@ -34,24 +50,79 @@ macro_rules! vector {
 /// ## Fields:
 /// ```rust
 /// ptr: NonNull<T> // Pointer to the Vector in memory.
-/// capacity: usize // Capacity of the Vector.
+/// capacity: usize // Capacity of the Vector, always `usize::MAX` for Zero-sized Types as they never allocate.
 /// length: usize // Length of the Vector.
+/// alloc: A // Allocator the buffer of the Vector is allocated with.
 /// ```
 #[derive(Debug)]
-pub struct Vector<T> {
+pub struct Vector<T, A: RawAlloc = Global> {
     ptr: NonNull<T>,
     capacity: usize,
     length: usize,
+    alloc: A,
+}
+
+
+/// Error returned when reserving memory for a `Vector` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested capacity exceeds the maximum size of an allocation.
+    CapacityOverflow,
+
+    /// The allocator could not provide memory for the requested `layout`.
+    AllocError { layout: Layout },
 }
 
 
 impl<T> Vector<T> {
     pub const fn new() -> Self {
+        return Self::new_in(Global);
+    }
+
+    /// Constructs a new empty `Vector` with room for at least `capacity` values before reallocating.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = Vector::with_capacity(10);
+    /// assert_eq!(vector.capacity(), 10);
+    /// for i in 0 .. 10 { vector.push(i); }
+    /// assert_eq!(vector.capacity(), 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        return Self::with_capacity_in(capacity, Global);
+    }
+}
+
+
+impl<T, A: RawAlloc> Vector<T, A> {
+    /// Constructs a new empty `Vector` whose buffer is allocated with `alloc`.
+    /// Nothing is allocated until a value is pushed.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32, Global> = Vector::new_in(Global);
+    /// vector.push(1);
+    /// assert_eq!(vector, vector![1]);
+    /// ```
+    pub const fn new_in(alloc: A) -> Self {
         return Self {
             ptr: NonNull::dangling(),
-            capacity: 0,
+            capacity: if size_of::<T>() == 0 { usize::MAX } else { 0 },
             length: 0,
-        }
+            alloc,
+        };
+    }
+
+    /// Constructs a new empty `Vector` whose buffer is allocated with `alloc`,
+    /// with room for at least `capacity` values before reallocating.
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        let mut vector = Self::new_in(alloc);
+        vector.reserve_exact(capacity);
+        return vector;
+    }
+
+    /// Returns a reference to the allocator of the `Vector`.
+    #[inline(always)]
+    pub const fn allocator(&self) -> &A {
+        return &self.alloc;
     }
 
     pub const fn capacity(&self) -> usize {
@@ -62,6 +133,91 @@ impl<T> Vector<T> {
         return self.length;
     }
 
+    pub const fn is_empty(&self) -> bool {
+        return self.length == 0;
+    }
+
+    /// Reserves room for at least `additional` more values, possibly more to avoid frequent reallocations.
+    /// Panics if the new capacity overflows.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1];
+    /// vector.reserve(10);
+    /// assert!(vector.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        if self.capacity - self.length >= additional { return; }
+
+        match self.amortized_capacity(additional) {
+            Ok(capacity) => self.resize_buffer(capacity),
+            Err(_) => panic!("Capacity overflow."),
+        }
+    }
+
+    /// Reserves room for exactly `additional` more values, unless the capacity already suffices.
+    /// Panics if the new capacity overflows.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1];
+    /// vector.reserve_exact(10);
+    /// assert_eq!(vector.capacity(), 11);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        if self.capacity - self.length >= additional { return; }
+        self.resize_buffer(self.length.checked_add(additional).expect("Capacity overflow."));
+    }
+
+    /// Tries to reserve room for at least `additional` more values, returning an error instead of panicking
+    /// if the capacity overflows or the allocator fails.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<u64> = Vector::new();
+    /// assert!(vector.try_reserve(100).is_ok());
+    /// assert_eq!(vector.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if self.capacity - self.length >= additional { return Ok(()); }
+
+        let capacity = self.amortized_capacity(additional)?;
+        return self.try_resize_buffer(capacity);
+    }
+
+    /// Tries to reserve room for exactly `additional` more values, returning an error instead of panicking
+    /// if the capacity overflows or the allocator fails.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if self.capacity - self.length >= additional { return Ok(()); }
+
+        let capacity = self.length.checked_add(additional).ok_or(TryReserveError::CapacityOverflow)?;
+        return self.try_resize_buffer(capacity);
+    }
+
+    /// Shrinks the capacity of the `Vector` to match its length, freeing the buffer entirely if it is empty.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = Vector::with_capacity(10);
+    /// vector.push(1);
+    /// vector.shrink_to_fit();
+    /// assert_eq!(vector.capacity(), 1);
+    /// ```
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    /// Shrinks the capacity of the `Vector` to the larger of `min_capacity` and its length.
+    /// Has no effect if the capacity is already smaller.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = Vector::with_capacity(10);
+    /// vector.push(1);
+    /// vector.shrink_to(4);
+    /// assert_eq!(vector.capacity(), 4);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let capacity = min_capacity.max(self.length);
+        if capacity < self.capacity { self.resize_buffer(capacity); }
+    }
+
     /// Pushs a new value into the `Vector`
     /// ## Example:
     /// ```rust
@@ -72,53 +228,234 @@ impl<T> Vector<T> {
     /// assert_eq!(vector, vector![4, 0, 4]);
     /// ```
     pub fn push(&mut self, value: T) {
-        assert_ne!(size_of::<T>(), 0, "Zero-sized Types are not allowed.");
-        
-        if self.capacity == 0 {
-            let layout = alloc::Layout::array::<T>(4)
-                .expect("Could not allocate memory.");
-            
-            let pointer = NonNull::new(unsafe { alloc::alloc(layout) } as *mut T)
-                .expect("Could not allocate memory.");
-
-            unsafe { pointer.as_ptr().write(value); }
-
-            self.ptr = pointer;
-            self.capacity = 4;
-            self.length = 1;
-        } else if self.length < self.capacity {
-            /* Note: Check may not be necessary. */
-            let offset = self.length
-                .checked_mul(size_of::<T>())
-                .expect("Cannot reach memory location.");
-            assert!(offset < isize::MAX as usize, "Wrapped isize.");
-            
-            unsafe { self.ptr.as_ptr().add(self.length).write(value); }
-            self.length += 1;
-        } else {
-            debug_assert!(self.length == self.capacity);
-            let new_capacity = self.capacity.checked_mul(2)
-                .expect("Capacity wrapped.");
-            
-            let size = size_of::<T>() * self.capacity;
-            let align = align_of::<T>();
-
-            size.checked_add(size % align)
-                .expect("Cannot allocate memory.");
+        if self.length == self.capacity { self.grow(); }
 
+        unsafe { self.ptr.as_ptr().add(self.length).write(value); }
+        self.length += 1;
+    }
+
+    /// Removes the last value from the `Vector` and returns it.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 2];
+    /// assert_eq!(vector.pop(), Some(2));
+    /// assert_eq!(vector.pop(), Some(1));
+    /// assert_eq!(vector.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.length == 0 { return None; }
+
+        self.length -= 1;
+        unsafe { return Some(ptr_read(self.ptr.as_ptr().add(self.length))); }
+    }
+
+    /// Inserts a value at `idx`, shifting every value after it to the right.
+    /// Panics if `idx` is greater than the length of the `Vector`.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 3];
+    /// vector.insert(1, 2);
+    /// assert_eq!(vector, vector![1, 2, 3]);
+    /// ```
+    pub fn insert(&mut self, idx: usize, value: T) {
+        assert!(idx <= self.length, "Insertion index (is {}) should be <= len (is {}).", idx, self.length);
+        if self.length == self.capacity { self.grow(); }
+
+        unsafe {
+            let pointer = self.ptr.as_ptr().add(idx);
+            ptr_copy(pointer, pointer.add(1), self.length - idx);
+            pointer.write(value);
+        }
+
+        self.length += 1;
+    }
+
+    /// Removes the value at `idx` and returns it, shifting every value after it to the left.
+    /// Panics if `idx` is out of bounds.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 2, 3];
+    /// assert_eq!(vector.remove(1), 2);
+    /// assert_eq!(vector, vector![1, 3]);
+    /// ```
+    pub fn remove(&mut self, idx: usize) -> T {
+        assert!(idx < self.length, "Removal index (is {}) should be < len (is {}).", idx, self.length);
+
+        unsafe {
+            let pointer = self.ptr.as_ptr().add(idx);
+            let value = ptr_read(pointer);
+            ptr_copy(pointer.add(1), pointer, self.length - idx - 1);
+            self.length -= 1;
+            return value;
+        }
+    }
+
+    /// Removes the value at `idx` and returns it, replacing it with the last value of the `Vector`.
+    /// Does not preserve ordering, but runs in `O(1)`. Panics if `idx` is out of bounds.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 2, 3, 4];
+    /// assert_eq!(vector.swap_remove(0), 1);
+    /// assert_eq!(vector, vector![4, 2, 3]);
+    /// ```
+    pub fn swap_remove(&mut self, idx: usize) -> T {
+        assert!(idx < self.length, "Removal index (is {}) should be < len (is {}).", idx, self.length);
+
+        unsafe {
+            let pointer = self.ptr.as_ptr();
+            let value = ptr_read(pointer.add(idx));
+            ptr_copy(pointer.add(self.length - 1), pointer.add(idx), 1);
+            self.length -= 1;
+            return value;
+        }
+    }
+
+    /// Shortens the `Vector` to `length` values, dropping the rest. Has no effect if `length` is not smaller.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 2, 3, 4];
+    /// vector.truncate(2);
+    /// assert_eq!(vector, vector![1, 2]);
+    /// ```
+    pub fn truncate(&mut self, length: usize) {
+        if length >= self.length { return; }
+
+        let remaining = self.length - length;
+        self.length = length;
+
+        unsafe { drop_in_place(slice_from_raw_parts_mut(self.ptr.as_ptr().add(length), remaining)); }
+    }
+
+    /// Drops every value within the `Vector`, keeping its capacity.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Keeps only the values for which `keep` returns `true`, preserving their order.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 2, 3, 4];
+    /// vector.retain(|x| x % 2 == 0);
+    /// assert_eq!(vector, vector![2, 4]);
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        let length = self.length;
+        let mut deleted = 0;
+
+        /* The length is zeroed while shifting, so a panic within `keep` leaks values instead of dropping them twice. */
+        self.length = 0;
+
+        for i in 0 .. length {
             unsafe {
-                let layout = alloc::Layout::from_size_align_unchecked(size, align);
-                let new_size = size_of::<T>() * new_capacity;
+                let pointer = self.ptr.as_ptr().add(i);
+
+                if !keep(&*pointer) {
+                    drop_in_place(pointer);
+                    deleted += 1;
+                } else if deleted > 0 {
+                    ptr_copy(pointer, pointer.sub(deleted), 1);
+                }
+            }
+        }
+
+        self.length = length - deleted;
+    }
+
+    /// Removes consecutive values for which `same_bucket` returns `true`, keeping the first of each run.
+    /// `same_bucket` receives the value being checked followed by the last value that was kept.
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        if self.length <= 1 { return; }
 
-                let pointer = NonNull::new(alloc::realloc(self.ptr.as_ptr() as *mut u8, layout, new_size) as *mut T)
-                    .expect("Could not re-allocate memory.");
-                pointer.as_ptr().add(self.length).write(value);
+        let length = self.length;
+        let mut kept = 1;
+        self.length = 0;
 
-                self.ptr = pointer;
-                self.capacity = new_capacity;
-                self.length += 1;
+        for i in 1 .. length {
+            unsafe {
+                let pointer = self.ptr.as_ptr();
+
+                if same_bucket(&mut *pointer.add(i), &mut *pointer.add(kept - 1)) {
+                    drop_in_place(pointer.add(i));
+                } else {
+                    ptr_copy(pointer.add(i), pointer.add(kept), 1);
+                    kept += 1;
+                }
             }
         }
+
+        self.length = kept;
+    }
+
+    /// Removes and returns the values within `range` as an `Iterator`, shifting the values after it to the left.
+    /// Values left within the `Drain` are dropped once it is dropped. Panics if `range` is out of bounds.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 2, 3, 4, 5];
+    /// let drained: Vec<i32> = vector.drain(1 .. 3).collect();
+    /// assert_eq!(drained, vec![2, 3]);
+    /// assert_eq!(vector, vector![1, 4, 5]);
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, A> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("Range start overflowed."),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("Range end overflowed."),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.length,
+        };
+
+        assert!(start <= end, "Drain start (is {}) should be <= end (is {}).", start, end);
+        assert!(end <= self.length, "Drain end (is {}) should be <= len (is {}).", end, self.length);
+
+        return Drain::new(self, start, end);
+    }
+
+    /// Splits the `Vector` in two at `at`, returning a new `Vector` containing the values from `at` onwards.
+    /// Panics if `at` is greater than the length of the `Vector`.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 2, 3];
+    /// let other = vector.split_off(1);
+    /// assert_eq!(vector, vector![1]);
+    /// assert_eq!(other, vector![2, 3]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self where A: Clone {
+        assert!(at <= self.length, "Split index (is {}) should be <= len (is {}).", at, self.length);
+
+        let count = self.length - at;
+        let mut other = Self::with_capacity_in(count, self.alloc.clone());
+
+        unsafe { ptr_copy_nonoverlapping(self.ptr.as_ptr().add(at), other.ptr.as_ptr(), count); }
+
+        self.length = at;
+        other.length = count;
+        return other;
+    }
+
+    /// Moves every value of `other` to the end of the `Vector`, leaving `other` empty.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 2];
+    /// let mut other: Vector<i32> = vector![3, 4];
+    /// vector.append(&mut other);
+    /// assert_eq!(vector, vector![1, 2, 3, 4]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append<B: RawAlloc>(&mut self, other: &mut Vector<T, B>) {
+        let count = other.length;
+        if count == 0 { return; }
+
+        self.reserve(count);
+
+        unsafe { ptr_copy_nonoverlapping(other.ptr.as_ptr(), self.ptr.as_ptr().add(self.length), count); }
+
+        other.length = 0;
+        self.length += count;
     }
 
     /// Returns a reference to the value at the given index if it exists.
@@ -131,29 +468,166 @@ impl<T> Vector<T> {
     }
 
     /// Returns a mutable reference to the value at the given index if it exists.
-    pub fn get_mut(&self, idx: usize) -> Option<&mut T> {
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
         if idx < self.length {
             unsafe { return Some(&mut *self.ptr.as_ptr().add(idx)); }
         }
         
         return None;
     }
+
+    /// Returns a raw pointer to the buffer of the `Vector`, which is dangling if nothing has been allocated.
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *const T {
+        return self.ptr.as_ptr();
+    }
+
+    /// Returns a mutable raw pointer to the buffer of the `Vector`, which is dangling if nothing has been allocated.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        return self.ptr.as_ptr();
+    }
+
+    /// Returns a slice over every value within the `Vector`.
+    /// ## Example:
+    /// ```rust
+    /// let vector: Vector<i32> = vector![1, 2, 3];
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+    /// ```
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { return slice::from_raw_parts(self.ptr.as_ptr(), self.length); }
+    }
+
+    /// Returns a mutable slice over every value within the `Vector`.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { return slice::from_raw_parts_mut(self.ptr.as_ptr(), self.length); }
+    }
+
+    /// Returns an iterator over references to the values of the `Vector`.
+    /// ## Example:
+    /// ```rust
+    /// let vector: Vector<i32> = vector![1, 2, 3];
+    /// let mut iter = vector.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next_back(), Some(&3));
+    /// ```
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        return Iter::new(self.as_slice());
+    }
+
+    /// Returns an iterator over mutable references to the values of the `Vector`.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 2, 3];
+    /// for value in vector.iter_mut() { *value *= 2; }
+    /// assert_eq!(vector, vector![2, 4, 6]);
+    /// ```
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        return IterMut::new(self.as_mut_slice());
+    }
 }
 
 
-impl<T: PartialEq> Vector<T> {
-    /// Searches through the `Vector` to find a value that matches `finding`, returning its index if found.
-    pub fn search(&self, finding: T) -> Option<usize> {
-        for i in 0 .. self.length {
-            if self.get(i).unwrap() == &finding {
-                return Some(i);
+/* Private Methods */
+impl<T, A: RawAlloc> Vector<T, A> {
+    /// Grows the capacity of the `Vector`, allocating `4` values at first and doubling afterwards.
+    #[inline]
+    fn grow(&mut self) {
+        self.reserve(1);
+    }
+
+    /// Returns the capacity the `Vector` should grow to when at least `additional` more values are required.
+    fn amortized_capacity(&self, additional: usize) -> Result<usize, TryReserveError> {
+        let required = self.length.checked_add(additional).ok_or(TryReserveError::CapacityOverflow)?;
+        return Ok(required.max(self.capacity.saturating_mul(2)).max(4));
+    }
+
+    /// Allocates, reallocates or frees the buffer of the `Vector` so it holds exactly `capacity` values.
+    /// Zero-sized Types never need a buffer, so this does nothing for them.
+    fn try_resize_buffer(&mut self, capacity: usize) -> Result<(), TryReserveError> {
+        debug_assert!(capacity >= self.length);
+        if size_of::<T>() == 0 { return Ok(()); }
+
+        if capacity == 0 {
+            if self.capacity != 0 {
+                unsafe { self.alloc.deallocate(self.ptr.cast(), Self::layout(self.capacity)); }
             }
+
+            self.ptr = NonNull::dangling();
+            self.capacity = 0;
+            return Ok(());
         }
-        return None;
+
+        let layout = Layout::array::<T>(capacity)
+            .map_err(|_| TryReserveError::CapacityOverflow)?;
+
+        let pointer = unsafe {
+            match self.capacity {
+                0 => self.alloc.allocate(layout),
+                _ => self.alloc.reallocate(self.ptr.cast(), Self::layout(self.capacity), layout.size()),
+            }
+        };
+
+        self.ptr = pointer.ok_or(TryReserveError::AllocError { layout })?.cast();
+        self.capacity = capacity;
+        return Ok(());
+    }
+
+    /// Resizes the buffer of the `Vector` to hold exactly `capacity` values, panicking if it fails.
+    fn resize_buffer(&mut self, capacity: usize) {
+        match self.try_resize_buffer(capacity) {
+            Ok(()) => {  },
+            Err(TryReserveError::CapacityOverflow) => panic!("Capacity overflow."),
+            Err(TryReserveError::AllocError { layout }) => handle_alloc_error(layout),
+        }
+    }
+
+    /// Returns the memory layout of a buffer holding `capacity` values.
+    #[inline(always)]
+    fn layout(capacity: usize) -> Layout {
+        unsafe { return Layout::from_size_align_unchecked(size_of::<T>() * capacity, align_of::<T>()); }
+    }
+}
+
+
+impl<T: PartialEq, A: RawAlloc> Vector<T, A> {
+    /// Removes consecutive equal values, keeping the first of each run.
+    /// ## Example:
+    /// ```rust
+    /// let mut vector: Vector<i32> = vector![1, 1, 2, 2, 2, 1];
+    /// vector.dedup();
+    /// assert_eq!(vector, vector![1, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Searches through the `Vector` to find a value that matches `finding`, returning its index if found.
+    pub fn search(&self, finding: T) -> Option<usize> {
+        return self.iter().position(|value| *value == finding);
+    }
+}
+
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            TryReserveError::CapacityOverflow => write!(f, "Capacity overflow."),
+            TryReserveError::AllocError { layout } => write!(f, "Could not allocate {} bytes of memory.", layout.size()),
+        };
     }
 }
 
 
+#[cfg(feature = "std")]
+impl std::error::Error for TryReserveError {  }
+
+
 impl<T> Default for Vector<T> {
     fn default() -> Self {
         return Self::new();
@@ -161,161 +635,167 @@ impl<T> Default for Vector<T> {
 }
 
 
-impl<T> Drop for Vector<T> {
+impl<T, A: RawAlloc> Drop for Vector<T, A> {
     fn drop(&mut self) {
         unsafe {
-            drop_in_place(
-        slice_from_raw_parts_mut(self.ptr.as_ptr(), self.length)
-            );
+            drop_in_place(slice_from_raw_parts_mut(self.ptr.as_ptr(), self.length));
 
-            let layout = alloc::Layout::from_size_align_unchecked(
-                size_of::<T>() * self.capacity,
-                align_of::<T>(),
-            );
-
-            alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout)
+            if size_of::<T>() != 0 && self.capacity != 0 {
+                self.alloc.deallocate(self.ptr.cast(), Self::layout(self.capacity));
+            }
         }
     }
 }
 
 
-impl<T: PartialEq> PartialEq for Vector<T> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.length != other.len() { return false; }
+impl<T: PartialEq, A: RawAlloc, B: RawAlloc> PartialEq<Vector<T, B>> for Vector<T, A> {
+    fn eq(&self, other: &Vector<T, B>) -> bool {
+        return self.as_slice() == other.as_slice();
+    }
+}
+
+
+impl<T: Eq, A: RawAlloc> Eq for Vector<T, A> {  }
 
-        for i in 0 .. self.length {
-            let (sget, oget) = (self.get(i), other.get(i));
-            if sget.is_some() != sget.is_some() { return false; }
-            if sget.is_some() {
-                if sget.unwrap() != oget.unwrap() { return false; }
-            }
+
+impl<T: PartialEq, A: RawAlloc> PartialEq<[T]> for Vector<T, A> {
+    fn eq(&self, other: &[T]) -> bool {
+        return self.as_slice() == other;
+    }
+}
+
+
+impl<T: PartialEq, A: RawAlloc, const N: usize> PartialEq<[T; N]> for Vector<T, A> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        return self.as_slice() == other;
+    }
+}
+
+
+impl<T, A: RawAlloc> Deref for Vector<T, A> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        return self.as_slice();
+    }
+}
+
+
+impl<T, A: RawAlloc> DerefMut for Vector<T, A> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        return self.as_mut_slice();
+    }
+}
+
+
+impl<T, A: RawAlloc> AsRef<[T]> for Vector<T, A> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        return self.as_slice();
+    }
+}
+
+
+impl<T, A: RawAlloc> AsMut<[T]> for Vector<T, A> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [T] {
+        return self.as_mut_slice();
+    }
+}
+
+
+impl<T, A: RawAlloc> Borrow<[T]> for Vector<T, A> {
+    #[inline(always)]
+    fn borrow(&self) -> &[T] {
+        return self.as_slice();
+    }
+}
+
+
+impl<T, A: RawAlloc> BorrowMut<[T]> for Vector<T, A> {
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        return self.as_mut_slice();
+    }
+}
+
+
+impl<T, A: RawAlloc, I: SliceIndex<[T]>> Index<I> for Vector<T, A> {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &Self::Output {
+        return &self.as_slice()[index];
+    }
+}
+
+
+impl<T, A: RawAlloc, I: SliceIndex<[T]>> IndexMut<I> for Vector<T, A> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        return &mut self.as_mut_slice()[index];
+    }
+}
+
+
+impl<T> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vector = Self::new();
+        vector.extend(iter);
+        return vector;
+    }
+}
+
+
+impl<T, A: RawAlloc> Extend<T> for Vector<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for value in iter {
+            self.push(value);
         }
+    }
+}
+
 
-        return true;
+impl<'a, T: Copy + 'a, A: RawAlloc> Extend<&'a T> for Vector<T, A> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
     }
 }
 
 
-impl<T> Index<usize> for Vector<T> {
-    type Output = T;
+impl<T, A: RawAlloc> IntoIterator for Vector<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
 
-    fn index(&self, index: usize) -> &Self::Output {
-        return self.get(index).unwrap();
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return IntoIter::new(self);
     }
 }
 
 
-impl<T> IndexMut<usize> for Vector<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        return self.get_mut(index).unwrap();
+impl<'a, T, A: RawAlloc> IntoIterator for &'a Vector<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
     }
 }
 
 
-#[cfg(test)]
-mod tests {
-    use super::Vector;
-
-    #[test]
-    fn create_vector() {
-        let mut vec: Vector<&str> = Vector::new();
-        vec.push("One");
-        vec.push("Two");
-        vec.push("Three");
-        vec.push("Four");
-        vec.push("Five");
-        assert_eq!(vec.capacity(), 8);
-        assert_eq!(vec.len(), 5);
-
-        let mut vec: Vector<i32> = Vector::new();
-        vec.push(1);
-        vec.push(2);
-        vec.push(3);
-        vec.push(4);
-        vec.push(5);
-        assert_eq!(vec.capacity(), 8);
-        assert_eq!(vec.len(), 5);
-
-        let mut vec: Vector<f32> = Vector::new();
-        vec.push(1.0);
-        vec.push(2.0);
-        vec.push(3.0);
-        vec.push(4.0);
-        vec.push(5.0);
-        assert_eq!(vec.capacity(), 8);
-        assert_eq!(vec.len(), 5);
-    }
-
-    #[test]
-    fn get() {
-        let vec: Vector<f32> = vector![1.0, 2.0, 3.0, 4.0, 5.0];
-        assert_eq!(vec.get(2), Some(&3.0));
-
-        let vec: Vector<&str> = vector!["Hey", "You", "should", "get", "ME!"];
-        assert_eq!(vec.get(4), Some(&"ME!"));
-
-        let vec: Vector<i32> = vector![1, 2, 3, 4, 5];
-        assert_eq!(vec.get(2), Some(&3));
-    }
-
-    #[test]
-    fn get_mut() {
-        let vec: Vector<i32> = vector![1, 2, 3, 4, 5];
-        let got = vec.get_mut(2).unwrap();
-        assert_eq!(vec.get_mut(2), Some(&mut 3));
-        *got = 72;
-        assert_eq!(vec.get_mut(2), Some(&mut 72));
-
-        let vec: Vector<f32> = vector![1.0, 2.0, 3.0, 4.0, 5.0];
-        let got = vec.get_mut(2).unwrap();
-        assert_eq!(vec.get_mut(2), Some(&mut 3.0));
-        *got = 72.0;
-        assert_eq!(vec.get_mut(2), Some(&mut 72.0));
-
-        let vec: Vector<&str> = vector!["Hey", "You", "should", "get", "ME!"];
-        let got = vec.get_mut(4).unwrap();
-        assert_eq!(vec.get_mut(4), Some(&mut "ME!"));
-        *got = "ME! But mutable..";
-        assert_eq!(vec.get_mut(4), Some(&mut "ME! But mutable.."));
-    }
-
-    #[test]
-    fn search() {
-        let vec: Vector<i32> = vector![1337, 420, 3005, 666, 23];
-        assert_eq!(vec.search(666), Some(3));
-
-        let vec: Vector<f32> = vector![3.14, 3.60, 5.55, 7.20, 45.0];
-        assert_eq!(vec.search(5.55), Some(2));
-
-        let vec: Vector<&str> = vector!["Hey", "You", "maybe", "find", "this."];
-        assert_eq!(vec.search("this."), Some(4));
-    }
-
-    #[test]
-    fn partial_eq() {
-        let vec_one: Vector<i32> = vector![5, 0, 5, 7, 8, 8];
-        let vec_two: Vector<i32> = vector![5, 0, 5, 7, 8, 8];
-        assert_eq!(vec_one, vec_two);
-        assert_ne!(vec_one, vector![0, 3, 0, 0, 0, 0]);
-    }
-
-    #[test]
-    fn index() {
-        let vec: Vector<&str> = vector!["Hey", "this", "is", "a", "Vector"];
-        assert_eq!(vec[0], "Hey");
-        assert_eq!(vec[1], "this");
-        assert_eq!(vec[2], "is");
-        assert_eq!(vec[3], "a");
-        assert_eq!(vec[4], "Vector");
-    }
-
-    #[test]
-    fn index_mut() {
-        let mut vec: Vector<i32> = vector![3, 3, 3];
-        assert_eq!(vec, vector![3, 3, 3]);
-        vec[0] = 6;
-        vec[1] = 6;
-        vec[2] = 6;
-        assert_eq!(vec, vector![6, 6, 6]);
-    }
-}
\ No newline at end of file
+impl<'a, T, A: RawAlloc> IntoIterator for &'a mut Vector<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter_mut();
+    }
+}
@@ -0,0 +1,59 @@
+use core::ptr::{NonNull, copy_nonoverlapping as ptr_copy_nonoverlapping};
+use core::option::Option;
+use alloc::alloc::{alloc as heap_alloc, dealloc as heap_dealloc, realloc as heap_realloc, Layout};
+
+
+/// Source of memory for the buffer of a `Vector`, a stable stand-in for `std::alloc::Allocator`.
+/// The `Vector` only ever requests layouts with a non-zero size.
+///
+/// # Safety
+/// Memory returned by `allocate` or `reallocate` must be valid for `layout` and stay valid until it is passed
+/// to `deallocate` or `reallocate`, even if the allocator itself is moved.
+pub unsafe trait RawAlloc {
+    /// Allocates a block of memory fitting `layout`, returning `None` if the allocation fails.
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>>;
+
+    /// Frees the block of memory at `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must have been allocated by this allocator with `layout`.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// Resizes the block of memory at `ptr` to `new_size` bytes, keeping the alignment of `layout`,
+    /// returning `None` if it fails in which case the old block is left untouched.
+    /// Allocates a new block, copies the values over and frees the old block by default.
+    ///
+    /// # Safety
+    /// `ptr` must have been allocated by this allocator with `layout`.
+    unsafe fn reallocate(&self, ptr: NonNull<u8>, layout: Layout, new_size: usize) -> Option<NonNull<u8>> {
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+        let new_ptr = self.allocate(new_layout)?;
+
+        ptr_copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), layout.size().min(new_size));
+        self.deallocate(ptr, layout);
+        return Some(new_ptr);
+    }
+}
+
+
+/// The global memory allocator, used by `Vector` unless another `RawAlloc` is given.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Global;
+
+
+unsafe impl RawAlloc for Global {
+    #[inline(always)]
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        unsafe { return NonNull::new(heap_alloc(layout)); }
+    }
+
+    #[inline(always)]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        heap_dealloc(ptr.as_ptr(), layout);
+    }
+
+    #[inline(always)]
+    unsafe fn reallocate(&self, ptr: NonNull<u8>, layout: Layout, new_size: usize) -> Option<NonNull<u8>> {
+        return NonNull::new(heap_realloc(ptr.as_ptr(), layout, new_size));
+    }
+}
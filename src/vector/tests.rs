@@ -0,0 +1,637 @@
+use super::{Vector, TryReserveError, RawAlloc, Global};
+use crate::vector;
+use std::rc::Rc;
+use std::cell::Cell;
+use std::alloc::Layout;
+use std::ptr::NonNull;
+
+
+#[test]
+fn create_vector() {
+    let mut vec: Vector<&str> = Vector::new();
+    vec.push("One");
+    vec.push("Two");
+    vec.push("Three");
+    vec.push("Four");
+    vec.push("Five");
+    assert_eq!(vec.capacity(), 8);
+    assert_eq!(vec.len(), 5);
+
+    let mut vec: Vector<i32> = Vector::new();
+    vec.push(1);
+    vec.push(2);
+    vec.push(3);
+    vec.push(4);
+    vec.push(5);
+    assert_eq!(vec.capacity(), 8);
+    assert_eq!(vec.len(), 5);
+
+    let mut vec: Vector<f32> = Vector::new();
+    vec.push(1.0);
+    vec.push(2.0);
+    vec.push(3.0);
+    vec.push(4.0);
+    vec.push(5.0);
+    assert_eq!(vec.capacity(), 8);
+    assert_eq!(vec.len(), 5);
+}
+
+#[test]
+fn get() {
+    let vec: Vector<f32> = vector![1.0, 2.0, 3.0, 4.0, 5.0];
+    assert_eq!(vec.get(2), Some(&3.0));
+
+    let vec: Vector<&str> = vector!["Hey", "You", "should", "get", "ME!"];
+    assert_eq!(vec.get(4), Some(&"ME!"));
+
+    let vec: Vector<i32> = vector![1, 2, 3, 4, 5];
+    assert_eq!(vec.get(2), Some(&3));
+}
+
+#[test]
+fn get_mut() {
+    let mut vec: Vector<i32> = vector![1, 2, 3, 4, 5];
+    assert_eq!(vec.get_mut(2), Some(&mut 3));
+    let got = vec.get_mut(2).unwrap();
+    *got = 72;
+    assert_eq!(vec.get_mut(2), Some(&mut 72));
+
+    let mut vec: Vector<f32> = vector![1.0, 2.0, 3.0, 4.0, 5.0];
+    assert_eq!(vec.get_mut(2), Some(&mut 3.0));
+    let got = vec.get_mut(2).unwrap();
+    *got = 72.0;
+    assert_eq!(vec.get_mut(2), Some(&mut 72.0));
+
+    let mut vec: Vector<&str> = vector!["Hey", "You", "should", "get", "ME!"];
+    assert_eq!(vec.get_mut(4), Some(&mut "ME!"));
+    let got = vec.get_mut(4).unwrap();
+    *got = "ME! But mutable..";
+    assert_eq!(vec.get_mut(4), Some(&mut "ME! But mutable.."));
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn search() {
+    let vec: Vector<i32> = vector![1337, 420, 3005, 666, 23];
+    assert_eq!(vec.search(666), Some(3));
+
+    let vec: Vector<f32> = vector![3.14, 3.60, 5.55, 7.20, 45.0];
+    assert_eq!(vec.search(5.55), Some(2));
+
+    let vec: Vector<&str> = vector!["Hey", "You", "maybe", "find", "this."];
+    assert_eq!(vec.search("this."), Some(4));
+}
+
+#[test]
+fn partial_eq() {
+    let vec_one: Vector<i32> = vector![5, 0, 5, 7, 8, 8];
+    let vec_two: Vector<i32> = vector![5, 0, 5, 7, 8, 8];
+    assert_eq!(vec_one, vec_two);
+    assert_ne!(vec_one, vector![0, 3, 0, 0, 0, 0]);
+}
+
+#[test]
+fn index() {
+    let vec: Vector<&str> = vector!["Hey", "this", "is", "a", "Vector"];
+    assert_eq!(vec[0], "Hey");
+    assert_eq!(vec[1], "this");
+    assert_eq!(vec[2], "is");
+    assert_eq!(vec[3], "a");
+    assert_eq!(vec[4], "Vector");
+}
+
+#[test]
+fn index_mut() {
+    let mut vec: Vector<i32> = vector![3, 3, 3];
+    assert_eq!(vec, vector![3, 3, 3]);
+    vec[0] = 6;
+    vec[1] = 6;
+    vec[2] = 6;
+    assert_eq!(vec, vector![6, 6, 6]);
+}
+
+#[test]
+fn pop() {
+    let mut vec: Vector<i32> = vector![1, 2, 3];
+    assert_eq!(vec.pop(), Some(3));
+    assert_eq!(vec.pop(), Some(2));
+    vec.push(4);
+    assert_eq!(vec, vector![1, 4]);
+    assert_eq!(vec.pop(), Some(4));
+    assert_eq!(vec.pop(), Some(1));
+    assert_eq!(vec.pop(), None);
+}
+
+#[test]
+fn insert() {
+    let mut vec: Vector<i32> = vector![2, 4];
+    vec.insert(0, 1);
+    vec.insert(2, 3);
+    vec.insert(4, 5);
+    vec.insert(5, 6);
+    assert_eq!(vec, vector![1, 2, 3, 4, 5, 6]);
+
+    let mut vec: Vector<&str> = Vector::new();
+    vec.insert(0, "First");
+    assert_eq!(vec, vector!["First"]);
+}
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds() {
+    let mut vec: Vector<i32> = vector![1];
+    vec.insert(2, 0);
+}
+
+#[test]
+fn remove() {
+    let mut vec: Vector<i32> = vector![1, 2, 3, 4];
+    assert_eq!(vec.remove(1), 2);
+    assert_eq!(vec.remove(2), 4);
+    assert_eq!(vec.remove(0), 1);
+    assert_eq!(vec, vector![3]);
+}
+
+#[test]
+#[should_panic]
+fn remove_out_of_bounds() {
+    let mut vec: Vector<i32> = vector![1];
+    vec.remove(1);
+}
+
+#[test]
+fn swap_remove() {
+    let mut vec: Vector<i32> = vector![1, 2, 3, 4];
+    assert_eq!(vec.swap_remove(1), 2);
+    assert_eq!(vec, vector![1, 4, 3]);
+    assert_eq!(vec.swap_remove(2), 3);
+    assert_eq!(vec, vector![1, 4]);
+}
+
+#[test]
+fn truncate() {
+    let mut vec: Vector<i32> = vector![1, 2, 3, 4];
+    vec.truncate(10);
+    assert_eq!(vec.len(), 4);
+    vec.truncate(1);
+    assert_eq!(vec, vector![1]);
+    assert_eq!(vec.capacity(), 4);
+}
+
+#[test]
+fn clear() {
+    let mut vec: Vector<&str> = vector!["Clear", "me"];
+    vec.clear();
+    assert!(vec.is_empty());
+    assert_eq!(vec.capacity(), 4);
+}
+
+#[test]
+fn retain() {
+    let mut vec: Vector<i32> = vector![1, 2, 3, 4, 5, 6];
+    vec.retain(|x| x % 3 != 0);
+    assert_eq!(vec, vector![1, 2, 4, 5]);
+    vec.retain(|_| false);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn dedup() {
+    let mut vec: Vector<i32> = vector![1, 1, 2, 3, 3, 3, 1, 1];
+    vec.dedup();
+    assert_eq!(vec, vector![1, 2, 3, 1]);
+
+    let mut vec: Vector<&str> = vector!["a", "A", "b", "B", "b"];
+    vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    assert_eq!(vec, vector!["a", "b"]);
+}
+
+#[test]
+fn drain() {
+    let mut vec: Vector<i32> = vector![1, 2, 3, 4, 5, 6];
+    assert_eq!(vec.drain(1 .. 3).collect::<Vec<_>>(), vec![2, 3]);
+    assert_eq!(vec, vector![1, 4, 5, 6]);
+
+    let mut drain = vec.drain(1 ..= 2);
+    assert_eq!(drain.len(), 2);
+    assert_eq!(drain.next_back(), Some(5));
+    drop(drain);
+    assert_eq!(vec, vector![1, 6]);
+
+    assert_eq!(vec.drain(..).collect::<Vec<_>>(), vec![1, 6]);
+    assert!(vec.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn drain_out_of_bounds() {
+    let mut vec: Vector<i32> = vector![1, 2];
+    vec.drain(1 .. 3);
+}
+
+#[test]
+fn split_off() {
+    let mut vec: Vector<i32> = vector![1, 2, 3, 4, 5];
+    let other = vec.split_off(2);
+    assert_eq!(vec, vector![1, 2]);
+    assert_eq!(other, vector![3, 4, 5]);
+    assert!(vec.split_off(2).is_empty());
+    assert_eq!(vec.split_off(0), vector![1, 2]);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn append() {
+    let mut vec: Vector<i32> = vector![1, 2, 3];
+    let mut other: Vector<i32> = vector![4, 5, 6, 7, 8, 9];
+    vec.append(&mut other);
+    assert_eq!(vec, vector![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert!(other.is_empty());
+
+    let mut empty: Vector<i32> = Vector::new();
+    empty.append(&mut vec);
+    assert_eq!(empty.len(), 9);
+}
+
+#[test]
+fn removed_values_are_dropped_once() {
+    let value = Rc::new(());
+    let mut vec: Vector<Rc<()>> = Vector::new();
+    for _ in 0 .. 20 { vec.push(Rc::clone(&value)); }
+
+    drop(vec.pop());
+    drop(vec.remove(0));
+    drop(vec.swap_remove(0));
+    vec.truncate(15);
+    vec.retain(|_| false);
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    for _ in 0 .. 20 { vec.push(Rc::clone(&value)); }
+    let mut drain = vec.drain(5 .. 15);
+    drop(drain.next());
+    drop(drain);
+    assert_eq!(Rc::strong_count(&value), 11);
+
+    vec.dedup_by(|_, _| true);
+    assert_eq!(Rc::strong_count(&value), 2);
+
+    drop(vec);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn with_capacity() {
+    let mut vec: Vector<i32> = Vector::with_capacity(6);
+    assert_eq!(vec.capacity(), 6);
+    assert!(vec.is_empty());
+
+    for i in 0 .. 6 { vec.push(i); }
+    assert_eq!(vec.capacity(), 6);
+    vec.push(6);
+    assert_eq!(vec.capacity(), 12);
+
+    let vec: Vector<i32> = Vector::with_capacity(0);
+    assert_eq!(vec.capacity(), 0);
+}
+
+#[test]
+fn reserve() {
+    let mut vec: Vector<i32> = vector![1, 2, 3];
+    vec.reserve(1);
+    assert_eq!(vec.capacity(), 4);
+    vec.reserve(2);
+    assert_eq!(vec.capacity(), 8);
+    vec.reserve(20);
+    assert_eq!(vec.capacity(), 23);
+    assert_eq!(vec, vector![1, 2, 3]);
+}
+
+#[test]
+fn reserve_exact() {
+    let mut vec: Vector<i32> = vector![1, 2, 3];
+    vec.reserve_exact(2);
+    assert_eq!(vec.capacity(), 5);
+    vec.reserve_exact(1);
+    assert_eq!(vec.capacity(), 5);
+}
+
+#[test]
+#[should_panic]
+fn reserve_overflow() {
+    let mut vec: Vector<i32> = vector![1];
+    vec.reserve(usize::MAX);
+}
+
+#[test]
+fn try_reserve() {
+    let mut vec: Vector<u64> = vector![1];
+    assert_eq!(vec.try_reserve(10), Ok(()));
+    assert!(vec.capacity() >= 11);
+
+    assert_eq!(vec.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+    assert_eq!(vec.try_reserve_exact(usize::MAX / 4), Err(TryReserveError::CapacityOverflow));
+    assert_eq!(vec, vector![1]);
+}
+
+#[test]
+fn shrink() {
+    let mut vec: Vector<i32> = Vector::with_capacity(16);
+    vec.push(1);
+    vec.push(2);
+
+    vec.shrink_to(8);
+    assert_eq!(vec.capacity(), 8);
+    vec.shrink_to(16);
+    assert_eq!(vec.capacity(), 8);
+    vec.shrink_to_fit();
+    assert_eq!(vec.capacity(), 2);
+    assert_eq!(vec, vector![1, 2]);
+
+    vec.clear();
+    vec.shrink_to_fit();
+    assert_eq!(vec.capacity(), 0);
+    vec.push(3);
+    assert_eq!(vec, vector![3]);
+}
+
+#[test]
+fn deref() {
+    let mut vec: Vector<i32> = vector![5, 3, 1, 4, 2];
+    assert!(vec.contains(&4));
+    assert_eq!(vec.iter().sum::<i32>(), 15);
+    assert_eq!(vec.first(), Some(&5));
+
+    vec.sort();
+    assert_eq!(vec, [1, 2, 3, 4, 5]);
+    assert_eq!(vec.binary_search(&4), Ok(3));
+    assert_eq!(vec.windows(2).count(), 4);
+    assert_eq!(vec.chunks(2).count(), 3);
+
+    for x in vec.iter_mut() { *x *= 2; }
+    assert_eq!(vec, [2, 4, 6, 8, 10]);
+}
+
+#[test]
+fn range_index() {
+    let mut vec: Vector<i32> = vector![1, 2, 3, 4, 5];
+    assert_eq!(&vec[1 .. 3], &[2, 3]);
+    assert_eq!(&vec[.. 2], &[1, 2]);
+    assert_eq!(&vec[3 ..], &[4, 5]);
+    assert_eq!(&vec[..], &[1, 2, 3, 4, 5]);
+
+    vec[1 ..= 2].copy_from_slice(&[0, 0]);
+    assert_eq!(vec, [1, 0, 0, 4, 5]);
+}
+
+#[test]
+#[should_panic]
+fn range_index_out_of_bounds() {
+    let vec: Vector<i32> = vector![1, 2, 3];
+    let _ = &vec[2 .. 4];
+}
+
+#[test]
+fn slice_interop() {
+    fn sum(values: &[i32]) -> i32 { values.iter().sum() }
+    fn sum_ref<S: AsRef<[i32]>>(values: S) -> i32 { values.as_ref().iter().sum() }
+    fn sum_borrow<S: std::borrow::Borrow<[i32]>>(values: S) -> i32 { values.borrow().iter().sum() }
+
+    let mut vec: Vector<i32> = vector![1, 2, 3];
+    assert_eq!(sum(&vec), 6);
+    assert_eq!(sum_ref(&vec), 6);
+    assert_eq!(sum_borrow(&vec[..]), 6);
+    assert_eq!(vec.as_slice(), &[1, 2, 3]);
+
+    vec.as_mut_slice().reverse();
+    vec.as_mut().swap(0, 1);
+    assert_eq!(vec, [2, 3, 1]);
+}
+
+#[test]
+fn as_ptr() {
+    let mut vec: Vector<i32> = vector![1, 2, 3];
+    unsafe {
+        assert_eq!(*vec.as_ptr().add(1), 2);
+        *vec.as_mut_ptr().add(2) = 7;
+    }
+    assert_eq!(vec, [1, 2, 7]);
+}
+
+#[test]
+fn iter() {
+    let vec: Vector<i32> = vector![1, 2, 3, 4];
+    let mut iter = vec.iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.as_slice(), &[2, 3]);
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let collected: Vec<&i32> = (&vec).into_iter().rev().collect();
+    assert_eq!(collected, vec![&4, &3, &2, &1]);
+}
+
+#[test]
+fn iter_mut() {
+    let mut vec: Vector<i32> = vector![1, 2, 3];
+    for value in &mut vec { *value += 10; }
+    assert_eq!(vec, [11, 12, 13]);
+
+    let mut iter = vec.iter_mut();
+    *iter.next_back().unwrap() = 0;
+    assert_eq!(iter.len(), 2);
+    assert_eq!(vec, [11, 12, 0]);
+}
+
+#[test]
+fn into_iter() {
+    let vec: Vector<String> = vector![String::from("a"), String::from("b"), String::from("c")];
+    let mut iter = vec.into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back().as_deref(), Some("c"));
+    assert_eq!(iter.as_slice(), &[String::from("a"), String::from("b")]);
+    assert_eq!(iter.next().as_deref(), Some("a"));
+    assert_eq!(iter.next().as_deref(), Some("b"));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn into_iter_drops_remaining_once() {
+    let rc = Rc::new(());
+    let vec: Vector<Rc<()>> = (0 .. 6).map(|_| rc.clone()).collect();
+    assert_eq!(Rc::strong_count(&rc), 7);
+
+    let mut iter = vec.into_iter();
+    let first = iter.next().unwrap();
+    let last = iter.next_back().unwrap();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    drop((first, last));
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let empty: Vector<Rc<()>> = Vector::new();
+    assert_eq!(empty.into_iter().count(), 0);
+}
+
+#[test]
+fn from_iter_and_extend() {
+    let mut vec: Vector<i32> = (1 ..= 3).collect();
+    assert_eq!(vec, [1, 2, 3]);
+
+    vec.extend(vec![4, 5]);
+    vec.extend(&[6, 7]);
+    assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7]);
+
+    let doubled: Vector<i32> = vec.into_iter().map(|x| x * 2).collect();
+    assert_eq!(doubled, [2, 4, 6, 8, 10, 12, 14]);
+}
+
+#[test]
+fn zero_sized_push_pop() {
+    let mut vec: Vector<()> = Vector::new();
+    assert_eq!(vec.capacity(), usize::MAX);
+
+    for _ in 0 .. 1000 { vec.push(()); }
+    vec.insert(500, ());
+    assert_eq!(vec.len(), 1001);
+    assert_eq!(vec.capacity(), usize::MAX);
+
+    assert_eq!(vec.remove(0), ());
+    assert_eq!(vec.pop(), Some(()));
+    assert_eq!(vec.len(), 999);
+
+    vec.shrink_to_fit();
+    vec.reserve(1000);
+    assert_eq!(vec.capacity(), usize::MAX);
+    assert_eq!(vec.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+
+    vec.clear();
+    assert_eq!(vec.pop(), None);
+}
+
+#[test]
+fn zero_sized_iterate() {
+    let mut vec: Vector<std::marker::PhantomData<u64>> = (0 .. 10).map(|_| std::marker::PhantomData).collect();
+    assert_eq!(vec.iter().count(), 10);
+    assert_eq!(vec.iter_mut().rev().count(), 10);
+    assert_eq!(vec.drain(2 .. 5).count(), 3);
+    assert_eq!(vec.len(), 7);
+
+    let mut iter = vec.into_iter();
+    assert_eq!(iter.len(), 7);
+    assert!(iter.next_back().is_some());
+    assert_eq!(iter.count(), 6);
+}
+
+#[test]
+fn zero_sized_drop() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROPPED: AtomicUsize = AtomicUsize::new(0);
+    struct Counted;
+
+    impl Drop for Counted {
+        fn drop(&mut self) { DROPPED.fetch_add(1, Ordering::SeqCst); }
+    }
+
+    let mut vec: Vector<Counted> = Vector::new();
+    for _ in 0 .. 8 { vec.push(Counted); }
+
+    drop(vec.pop());
+    vec.truncate(5);
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 3);
+
+    let mut iter = vec.into_iter();
+    drop(iter.next());
+    drop(iter);
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 8);
+}
+
+
+/// Allocator counting its allocations and deallocations, refusing any allocation larger than `limit` bytes.
+#[derive(Debug, Clone)]
+struct CountingAlloc {
+    allocations: Rc<Cell<usize>>,
+    deallocations: Rc<Cell<usize>>,
+    limit: usize,
+}
+
+
+impl CountingAlloc {
+    fn new(limit: usize) -> Self {
+        return Self { allocations: Rc::new(Cell::new(0)), deallocations: Rc::new(Cell::new(0)), limit };
+    }
+
+    fn live(&self) -> usize {
+        return self.allocations.get() - self.deallocations.get();
+    }
+}
+
+
+unsafe impl RawAlloc for CountingAlloc {
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        assert_ne!(layout.size(), 0);
+        if layout.size() > self.limit { return None; }
+
+        self.allocations.set(self.allocations.get() + 1);
+        return Global.allocate(layout);
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.deallocations.set(self.deallocations.get() + 1);
+        Global.deallocate(ptr, layout);
+    }
+}
+
+
+#[test]
+fn custom_allocator() {
+    let alloc = CountingAlloc::new(usize::MAX);
+    let mut vec: Vector<i32, CountingAlloc> = Vector::new_in(alloc.clone());
+    assert_eq!(alloc.allocations.get(), 0);
+
+    for i in 0 .. 100 { vec.push(i); }
+    assert!(alloc.allocations.get() > 1);
+    assert_eq!(alloc.live(), 1);
+    assert_eq!(vec.iter().sum::<i32>(), 4950);
+
+    let other = vec.split_off(50);
+    assert_eq!(alloc.live(), 2);
+    assert_eq!(other, (50 .. 100).collect::<Vector<i32>>());
+
+    vec.clear();
+    vec.shrink_to_fit();
+    assert_eq!(alloc.live(), 1);
+
+    let mut iter = other.into_iter();
+    assert_eq!(iter.next(), Some(50));
+    drop(iter);
+    drop(vec);
+    assert_eq!(alloc.live(), 0);
+    assert_eq!(alloc.allocations.get(), alloc.deallocations.get());
+}
+
+
+#[test]
+fn custom_allocator_failure() {
+    let alloc = CountingAlloc::new(64);
+    let mut vec: Vector<u64, CountingAlloc> = Vector::with_capacity_in(8, alloc.clone());
+    assert_eq!(vec.capacity(), 8);
+
+    let layout = Layout::array::<u64>(9).unwrap();
+    assert_eq!(vec.try_reserve_exact(9), Err(TryReserveError::AllocError { layout }));
+    assert_eq!(vec.capacity(), 8);
+
+    for i in 0 .. 8 { vec.push(i); }
+    assert!(vec.try_reserve(1).is_err());
+    assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    drop(vec);
+    assert_eq!(alloc.live(), 0);
+}